@@ -0,0 +1,167 @@
+use clap::Args;
+use tracing::warn;
+
+use crate::cli::core::download::record_download_result;
+use crate::core::data::database::{Database, DatabaseTrack, DownloadOutcome};
+use crate::core::files::lyrics::LyricsManager;
+use crate::core::infrastructure::cache::LyricsCacheInterface;
+use crate::core::files::scanner::Track;
+use crate::error::{LrcGetError, Result};
+use crate::services::SimpleServices;
+use crate::ui::browser::{BrowserAction, LibraryBrowser, LibraryFilter, StatusFilter};
+use crate::ui::tui::{is_interactive, next_key, TuiSession, TICK};
+
+#[derive(Args)]
+pub struct BrowseArgs {
+    /// Only list tracks whose artist contains this text
+    #[arg(short, long)]
+    artist: Option<String>,
+
+    /// Only list tracks whose album contains this text
+    #[arg(short = 'l', long)]
+    album: Option<String>,
+
+    /// Lyrics status to list (all, missing, synced, plain, instrumental)
+    #[arg(short, long, default_value = "all")]
+    status: String,
+}
+
+pub async fn execute(args: BrowseArgs, services: &SimpleServices) -> Result<()> {
+    if !is_interactive() {
+        return Err(LrcGetError::Validation("browse needs an interactive terminal".to_string()));
+    }
+
+    let status = StatusFilter::parse(&args.status).ok_or_else(|| {
+        LrcGetError::Validation(format!(
+            "Invalid status '{}'. Use all, missing, synced, plain or instrumental", args.status
+        ))
+    })?;
+
+    let mut db = services.create_database().await?;
+    let tracks = db.get_all_tracks().await?;
+    if tracks.is_empty() {
+        println!("No tracks in the library. Run 'lrcget scan' first.");
+        return Ok(());
+    }
+
+    let client = services.create_lrclib_client();
+    let cache = services.create_lyrics_cache()?;
+    let downloader = services.create_cached_downloader(cache.clone());
+    let lyrics_manager = LyricsManager::new();
+
+    let mut browser = LibraryBrowser::new(tracks, LibraryFilter {
+        artist: args.artist,
+        album: args.album,
+        status,
+        query: String::new(),
+    });
+
+    let mut session = TuiSession::enter()?;
+
+    loop {
+        session.terminal.draw(|frame| browser.draw(frame))?;
+
+        // Poll rather than block so the timing preview keeps moving
+        let Some(key) = next_key(TICK)? else { continue };
+
+        match browser.handle_key(key) {
+            BrowserAction::None => {}
+            BrowserAction::Quit => break,
+            BrowserAction::Reload => {
+                let tracks = db.get_all_tracks().await?;
+                browser.set_message(format!("Reloaded {} tracks", tracks.len()));
+                browser.set_tracks(tracks);
+            }
+            BrowserAction::Search(track) => {
+                browser.set_message(format!("Searching LRCLIB for {} - {}...", track.artist_name, track.title));
+                session.terminal.draw(|frame| browser.draw(frame))?;
+
+                match client.search(&track.title, &track.artist_name, &track.album_name, "").await {
+                    Ok(results) if results.is_empty() => {
+                        browser.set_message(format!("No results for {} - {}", track.artist_name, track.title));
+                    }
+                    Ok(results) => browser.open_picker(track, results),
+                    Err(e) => browser.set_message(format!("Search failed: {}", e)),
+                }
+            }
+            BrowserAction::Apply(track, result) => {
+                let download = downloader.apply_search_result(&track, &result).await;
+                record_download_result(&mut db, &track, &download).await;
+
+                match download {
+                    Ok(result) if result.rejected => {
+                        browser.set_message(format!(
+                            "Lyrics do not fit the track length, not applied: {} - {}", track.artist_name, track.title
+                        ));
+                    }
+                    Ok(_) => {
+                        refresh_track(&mut db, &mut browser, &track).await;
+                        browser.set_message(format!("Applied lyrics to {} - {}", track.artist_name, track.title));
+                    }
+                    Err(e) => browser.set_message(format!("Failed to save lyrics: {}", e)),
+                }
+            }
+            BrowserAction::Download(track) => {
+                browser.set_message(format!("Downloading lyrics for {} - {}...", track.artist_name, track.title));
+                session.terminal.draw(|frame| browser.draw(frame))?;
+
+                let download = downloader.download_for_track(&track).await;
+                record_download_result(&mut db, &track, &download).await;
+
+                let message = match &download {
+                    Ok(result) => match result.outcome() {
+                        DownloadOutcome::Synced => "Downloaded synced lyrics".to_string(),
+                        DownloadOutcome::Plain => "Downloaded plain lyrics".to_string(),
+                        DownloadOutcome::Instrumental => "Marked as instrumental".to_string(),
+                        DownloadOutcome::Mismatch => "Rejected lyrics that do not fit the track length".to_string(),
+                        DownloadOutcome::NotFound | DownloadOutcome::Error => "No lyrics found".to_string(),
+                    },
+                    Err(e) => format!("Download failed: {}", e),
+                };
+                refresh_track(&mut db, &mut browser, &track).await;
+                browser.set_message(format!("{}: {} - {}", message, track.artist_name, track.title));
+            }
+            BrowserAction::Delete(track) => {
+                // Embedded lyrics would show up again on the next read of the file
+                let removed = lyrics_manager.remove_sidecar_lyrics(&track.file_path).and_then(|sidecar| {
+                    Ok(lyrics_manager.remove_embedded_lyrics(&track.file_path)? || sidecar)
+                });
+
+                match removed {
+                    Ok(removed) => {
+                        refresh_track(&mut db, &mut browser, &track).await;
+                        browser.set_message(if removed {
+                            format!("Deleted lyrics of {} - {}", track.artist_name, track.title)
+                        } else {
+                            format!("No lyrics to delete for {} - {}", track.artist_name, track.title)
+                        });
+                    }
+                    Err(e) => browser.set_message(format!("Failed to delete lyrics: {}", e)),
+                }
+            }
+        }
+    }
+
+    drop(session);
+    if let Err(e) = cache.read().await.save_index().await {
+        warn!("Failed to save cache index: {}", e);
+    }
+
+    Ok(())
+}
+
+/// Re-read a track's file so the library and the browser show the lyrics now on disk
+async fn refresh_track(db: &mut Database, browser: &mut LibraryBrowser, track: &DatabaseTrack) {
+    let refreshed = match Track::new_from_path(std::path::Path::new(&track.file_path)) {
+        Ok(updated) => db.add_track(&updated).await,
+        Err(e) => Err(e),
+    };
+
+    match refreshed {
+        Ok(()) => match db.get_track(track.id).await {
+            Ok(updated) => browser.replace_track(updated),
+            Err(e) => browser.set_message(format!("Failed to reload track: {}", e)),
+        },
+        Err(e) => browser.set_message(format!("Failed to update library: {}", e)),
+    }
+}
@@ -2,8 +2,12 @@ use clap::Args;
 use tracing::{info, warn};
 
 use crate::services::ServiceFactory;
-use crate::core::services::lrclib::LyricsDownloader;
-use crate::core::infrastructure::cache::{LyricsCache, LyricsCacheInterface};
+use crate::core::services::concurrency::AdaptiveConcurrency;
+use crate::core::services::lrclib::LyricsDownloadResult;
+use crate::config::validation::ConfigValidator;
+use crate::core::data::database::{Database, DatabaseTrack, DownloadAttempt, DownloadOutcome, DownloadStatus};
+use crate::core::files::scanner::Track;
+use crate::core::infrastructure::cache::LyricsCacheInterface;
 use crate::core::infrastructure::hooks::{HookManager, HookEvent, HookContext};
 use crate::ui::create_progress_interface;
 use crate::ui::progress_state::{ProgressState, TrackResult, FinalStats};
@@ -12,6 +16,8 @@ use std::collections::HashMap;
 use std::sync::Arc;
 use tokio::sync::{RwLock, Mutex};
 
+const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
 #[derive(Args)]
 pub struct DownloadArgs {
     /// Download lyrics for specific track ID
@@ -22,6 +28,10 @@ pub struct DownloadArgs {
     #[arg(long)]
     missing_lyrics: bool,
 
+    /// Retry every track missing lyrics, ignoring the not-found retry schedule
+    #[arg(long)]
+    retry_all: bool,
+
     /// Filter by artist name
     #[arg(long)]
     artist: Option<String>,
@@ -30,10 +40,18 @@ pub struct DownloadArgs {
     #[arg(long)]
     album: Option<String>,
 
-    /// Maximum number of parallel downloads
+    /// Parallel downloads to start with; adjusted to LRCLIB's responses as the run goes
     #[arg(long, default_value = "4")]
     parallel: usize,
 
+    /// Fewest parallel downloads when LRCLIB slows down (default: min_parallel_downloads)
+    #[arg(long)]
+    min_parallel: Option<usize>,
+
+    /// Most parallel downloads when LRCLIB keeps up (default: max_parallel_downloads)
+    #[arg(long)]
+    max_parallel: Option<usize>,
+
     /// Dry run (don't actually download)
     #[arg(long)]
     dry_run: bool,
@@ -44,18 +62,15 @@ pub struct DownloadArgs {
 }
 
 pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crate::error::Result<()> {
+    let min_parallel = args.min_parallel.unwrap_or(config.min_parallel_downloads);
+    let max_parallel = args.max_parallel.unwrap_or(config.max_parallel_downloads);
+    ConfigValidator::validate_parallel_downloads(min_parallel, max_parallel)?;
+
     let factory = ServiceFactory::new(std::sync::Arc::new(config.clone()));
     let bundle = factory.create_full_bundle().await?;
 
     // Initialize hybrid cache (Redis + File)
-    let cache_dir = config.database_path.parent()
-        .unwrap_or(&config.database_path)
-        .join("cache");
-    let cache = Arc::new(RwLock::new(
-        LyricsCache::new(cache_dir, config.redis_url.as_deref())?
-    ));
-    let client = factory.create_lrclib_client();
-    let _downloader = LyricsDownloader::from_client_with_cache(client, cache.clone());
+    let cache = Arc::new(RwLock::new(config.create_lyrics_cache()?));
 
     // Initialize hooks
     let hooks_config_path = config.database_path.parent()
@@ -74,7 +89,19 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
 
         // Apply filters
         if args.missing_lyrics {
-            query_tracks.retain(|t| t.lrc_lyrics.is_none() && t.txt_lyrics.is_none());
+            query_tracks.retain(|t| t.is_missing_lyrics());
+
+            if !args.retry_all {
+                let statuses = bundle.database.get_download_statuses().await.map_err(crate::error::LrcGetError::Internal)?;
+                let now = chrono::Utc::now().timestamp();
+                let before = query_tracks.len();
+                query_tracks.retain(|t| is_retry_due(statuses.get(&t.id), &config.retry_schedule_days, now));
+
+                let skipped = before - query_tracks.len();
+                if skipped > 0 {
+                    info!("Skipping {} tracks not due for a re-check (use --retry-all to include them)", skipped);
+                }
+            }
         }
 
         if let Some(artist) = &args.artist {
@@ -88,6 +115,8 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
         query_tracks
     };
 
+    let database = Arc::new(Mutex::new(bundle.database));
+
     if tracks.is_empty() {
         warn!("No tracks found matching criteria");
         return Ok(());
@@ -121,7 +150,11 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
     // Setup progress tracking and signal handling
     let total = tracks.len();
     let mut ui = create_progress_interface();
-    let progress_state = ProgressState::new(total);
+    let mut progress_state = ProgressState::new(total);
+
+    // Every client reports its request latency and errors here; the limit follows them
+    let concurrency = Arc::new(AdaptiveConcurrency::new(args.parallel, min_parallel, max_parallel));
+    progress_state.concurrency = concurrency.limit();
 
     // Initialize signal handler
     let mut signal_handler = SignalHandler::new();
@@ -149,6 +182,7 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
     let ui_final = ui.clone();
     let cache_shared = cache.clone();
     let config_shared = config.clone();
+    let database_shared = database.clone();
 
     // Store signal handler for final cleanup
     let signal_handler_for_final = signal_handler.clone();
@@ -174,22 +208,21 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
     // Download lyrics with async parallel processing and signal handling
     use futures::stream::{self, StreamExt};
 
-    // Create a semaphore to control active downloads during pause/resume
-    let semaphore = Arc::new(tokio::sync::Semaphore::new(args.parallel));
-
+    // At most max_parallel tasks are polled; the adaptive limit decides how many download
     stream::iter(tracks)
-        .for_each_concurrent(args.parallel, move |track| {
+        .for_each_concurrent(max_parallel, move |track| {
             let progress_state = progress_state.clone();
             let ui = ui.clone();
             let cache = cache_shared.clone();
             let config = config_shared.clone();
+            let database = database_shared.clone();
             let signal_handler = signal_handler.clone();
-            let semaphore = semaphore.clone();
+            let concurrency = concurrency.clone();
             let is_terminal = is_terminal_ui;
 
             async move {
-                // Wait for semaphore permit and check if we should proceed
-                let _permit = semaphore.acquire().await.unwrap();
+                // Wait for room under the current limit and check if we should proceed
+                let _permit = concurrency.acquire().await;
 
                 // Check for pause/stop before starting
                 loop {
@@ -212,6 +245,7 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
                     let mut state = progress_state.lock().await;
                     state.current_operation = format!("Searching {}", track_name);
                     state.current_track = Some(track_name.clone());
+                    state.concurrency = concurrency.limit();
                 }
 
                 let mut ui_guard = ui.lock().await;
@@ -222,15 +256,19 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
                 drop(ui_guard);
 
                 // Create a fresh client for each task to avoid thread safety issues
-                let client = config.create_lrclib_client();
-                let downloader = LyricsDownloader::from_client_with_cache(client, cache);
+                let downloader = config.create_lyrics_downloader(Some(cache))
+                    .with_concurrency(concurrency.clone())
+                    .with_bypass_negative_cache(args.retry_all);
 
                 // Check if we should stop during download
                 if signal_handler.get_state() == AppState::Stopping {
                     return;
                 }
 
-                let result = match downloader.download_for_track_with_fuzzy(&track, args.fuzzy_search).await {
+                let download = downloader.download_for_track_with_fuzzy(&track, args.fuzzy_search).await;
+                record_attempt(&database, &track, &download).await;
+
+                let result = match download {
                     Ok(lyrics_info) => {
                         // Check again after download completes
                         if signal_handler.get_state() == AppState::Stopping {
@@ -239,9 +277,12 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
 
                         if !lyrics_info.found {
                             TrackResult::NotFound
+                        } else if lyrics_info.rejected {
+                            info!("✗ Rejected lyrics that do not fit the track length: {} - {}", track.artist_name, track.title);
+                            TrackResult::NotFound
                         } else if lyrics_info.instrumental {
                             info!("✓ Track is instrumental: {} - {}", track.artist_name, track.title);
-                            TrackResult::NotFound // Treat instrumental as not found for stats purposes
+                            TrackResult::Instrumental
                         } else if lyrics_info.synced_lyrics {
                             info!("✓ Downloaded synced lyrics for: {} - {}", track.artist_name, track.title);
                             TrackResult::SyncedLyrics
@@ -262,10 +303,12 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
                 {
                     let mut state = progress_state.lock().await;
                     state.processed_tracks += 1;
+                    state.concurrency = concurrency.limit();
 
                     match result {
                         TrackResult::SyncedLyrics => state.synced_tracks += 1,
                         TrackResult::PlainLyrics => state.plain_tracks += 1,
+                        TrackResult::Instrumental => state.instrumental_tracks += 1,
                         TrackResult::NotFound => state.missing_tracks += 1,
                         TrackResult::Error(_) => state.error_tracks += 1,
                     }
@@ -338,3 +381,154 @@ pub async fn execute(args: DownloadArgs, config: &crate::config::Config) -> crat
 
     Ok(())
 }
+
+/// Whether a track should be looked up again given its stored download status.
+///
+/// The schedule step is picked by the number of consecutive misses (rejected lyrics count
+/// as misses); instrumental results wait for the last (longest) step. Errors and tracks never attempted are always due.
+fn is_retry_due(status: Option<&DownloadStatus>, schedule_days: &[u32], now: i64) -> bool {
+    let status = match status {
+        Some(status) => status,
+        None => return true,
+    };
+
+    let step = match status.outcome {
+        DownloadOutcome::NotFound | DownloadOutcome::Mismatch => (status.consecutive_misses.max(1) - 1) as usize,
+        DownloadOutcome::Instrumental => schedule_days.len().saturating_sub(1),
+        _ => return true,
+    };
+
+    match schedule_days.get(step).or(schedule_days.last()) {
+        Some(&days) => now - status.last_attempt_at >= days as i64 * SECONDS_PER_DAY,
+        None => true,
+    }
+}
+
+/// Store the outcome of a download attempt and refresh the track's lyrics columns. The file
+/// is re-read before taking the lock, so parsing it does not hold up the other downloads.
+async fn record_attempt(
+    database: &Mutex<Database>,
+    track: &DatabaseTrack,
+    download: &anyhow::Result<LyricsDownloadResult>,
+) {
+    let (attempt, updated) = prepare_download_record(track, download);
+    let mut db = database.lock().await;
+    save_download_record(&mut db, track, &attempt, updated.as_ref()).await;
+}
+
+/// Record a download attempt and refresh the track's lyrics from disk when any were written
+pub(crate) async fn record_download_result(
+    db: &mut Database,
+    track: &DatabaseTrack,
+    download: &anyhow::Result<LyricsDownloadResult>,
+) {
+    let (attempt, updated) = prepare_download_record(track, download);
+    save_download_record(db, track, &attempt, updated.as_ref()).await;
+}
+
+/// The attempt to record and, when lyrics were written, the track re-read from disk so the
+/// library reflects the sidecar or embedded lyrics
+fn prepare_download_record(
+    track: &DatabaseTrack,
+    download: &anyhow::Result<LyricsDownloadResult>,
+) -> (DownloadAttempt, Option<Track>) {
+    let attempt = match download {
+        Ok(result) => DownloadAttempt {
+            track_id: track.id,
+            outcome: result.outcome(),
+            source: result.source.map(|source| source.as_str().to_string()),
+            lrclib_id: result.lrclib_id,
+            error: None,
+            confidence: result.confidence,
+        },
+        Err(e) => DownloadAttempt {
+            track_id: track.id,
+            outcome: DownloadOutcome::Error,
+            source: None,
+            lrclib_id: None,
+            error: Some(e.to_string()),
+            confidence: None,
+        },
+    };
+
+    let lyrics_written = matches!(attempt.outcome, DownloadOutcome::Synced | DownloadOutcome::Plain | DownloadOutcome::Instrumental);
+    let updated = if lyrics_written { reread_track(track) } else { None };
+
+    (attempt, updated)
+}
+
+async fn save_download_record(
+    db: &mut Database,
+    track: &DatabaseTrack,
+    attempt: &DownloadAttempt,
+    updated: Option<&Track>,
+) {
+    if let Err(e) = db.record_download_attempt(attempt).await {
+        warn!("Failed to record download status for {}: {}", track.title, e);
+    }
+
+    if let Some(updated) = updated {
+        store_reread_track(db, track, updated).await;
+    }
+}
+
+/// Re-read a track's file after its lyrics changed on disk and update the library row
+pub(crate) async fn refresh_library_track(db: &mut Database, track: &DatabaseTrack) {
+    if let Some(updated) = reread_track(track) {
+        store_reread_track(db, track, &updated).await;
+    }
+}
+
+/// Parse a track's file again; kept apart from the database write so it can run unlocked
+fn reread_track(track: &DatabaseTrack) -> Option<Track> {
+    match Track::new_from_path(std::path::Path::new(&track.file_path)) {
+        Ok(updated) => Some(updated),
+        Err(e) => {
+            warn!("Failed to re-read {}: {}", track.file_path, e);
+            None
+        }
+    }
+}
+
+async fn store_reread_track(db: &mut Database, track: &DatabaseTrack, updated: &Track) {
+    if let Err(e) = db.add_track(updated).await {
+        warn!("Failed to update lyrics for {}: {}", track.title, e);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn status(outcome: DownloadOutcome, consecutive_misses: u32, last_attempt_at: i64) -> DownloadStatus {
+        DownloadStatus {
+            track_id: 1,
+            last_attempt_at,
+            outcome,
+            source: None,
+            lrclib_id: None,
+            consecutive_misses,
+            confidence: None,
+        }
+    }
+
+    #[test]
+    fn test_retry_schedule_backs_off_with_misses() {
+        let schedule = [1, 7, 30];
+        let now = 100 * SECONDS_PER_DAY;
+        let days_ago = |days: i64| now - days * SECONDS_PER_DAY;
+
+        assert!(is_retry_due(None, &schedule, now));
+        assert!(is_retry_due(Some(&status(DownloadOutcome::Error, 3, now)), &schedule, now));
+
+        assert!(!is_retry_due(Some(&status(DownloadOutcome::NotFound, 1, now)), &schedule, now));
+        assert!(is_retry_due(Some(&status(DownloadOutcome::NotFound, 1, days_ago(1))), &schedule, now));
+        assert!(!is_retry_due(Some(&status(DownloadOutcome::NotFound, 2, days_ago(6))), &schedule, now));
+        assert!(is_retry_due(Some(&status(DownloadOutcome::NotFound, 2, days_ago(7))), &schedule, now));
+        assert!(!is_retry_due(Some(&status(DownloadOutcome::NotFound, 9, days_ago(29))), &schedule, now));
+        assert!(is_retry_due(Some(&status(DownloadOutcome::NotFound, 9, days_ago(30))), &schedule, now));
+
+        assert!(!is_retry_due(Some(&status(DownloadOutcome::Instrumental, 0, days_ago(7))), &schedule, now));
+        assert!(is_retry_due(Some(&status(DownloadOutcome::NotFound, 1, now)), &[], now));
+    }
+}
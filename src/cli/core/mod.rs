@@ -2,11 +2,12 @@
 //!
 //! This module contains the fundamental commands that users need for basic
 //! lyrics management: initializing libraries, scanning music files,
-//! downloading lyrics, and searching for lyrics.
+//! downloading lyrics, searching for lyrics and browsing the library.
 
 pub mod init;
 pub mod scan;
 pub mod download;
 pub mod search;
+pub mod browse;
 
 // Re-export for convenience
@@ -1,6 +1,10 @@
 use clap::Args;
-use tracing::info;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tracing::{debug, info, warn};
 
+use crate::core::data::database::{Database, DatabaseTrack, StoredFileState};
+use crate::core::files::scanner::{compute_content_hash, FileFingerprint, LyricsSource, Track};
 use crate::error::Result;
 use crate::services::SimpleServices;
 use crate::utils::progress::{ProgressUtils, ProgressMessages};
@@ -14,6 +18,14 @@ pub struct ScanArgs {
     /// Rescan all files (ignore existing entries)
     #[arg(short, long)]
     force: bool,
+
+    /// Store a SHA-256 content hash so touched but unchanged files are not re-read
+    #[arg(long)]
+    hash: bool,
+
+    /// Keep database entries for files that no longer exist
+    #[arg(long)]
+    no_prune: bool,
 }
 
 pub async fn execute(args: ScanArgs, services: &SimpleServices) -> Result<()> {
@@ -37,50 +49,90 @@ pub async fn execute(args: ScanArgs, services: &SimpleServices) -> Result<()> {
 
     if args.force {
         info!("Forcing rescan of all files...");
-        // TODO: Implement clear_tracks in DatabaseService
     }
 
+    // Known file fingerprints, also used to find files that vanished or moved
+    let known_files = database.get_file_states().await?;
+
     // Create progress bar using centralized utility
     let pb = ProgressUtils::create_scanning_spinner();
 
-    let mut total_tracks = 0;
-
+    let mut stats = ScanStats::default();
     let num_directories = directories.len();
-    
+
+    // List every root before matching, so a file moved from one root to another is a move
+    let mut listed = Vec::new();
     for directory in directories {
         pb.set_message(ProgressMessages::scanning_directory(&directory));
-        
-        let tracks = scanner.scan_directory(&std::path::PathBuf::from(&directory), &None).await?;
-        
-        if tracks.is_empty() {
-            pb.set_message(format!("No music files found in {}", directory));
+
+        let directory_path = PathBuf::from(&directory);
+        if !directory_path.is_dir() {
+            // An unmounted drive must not wipe its tracks from the library
+            warn!("Directory not found, skipping (nothing pruned): {}", directory);
             continue;
         }
-        
-        pb.set_message(format!("Processing {} tracks from {}", tracks.len(), directory));
-        
+
+        let paths = scanner.list_audio_files(&directory_path, &None)?;
+        stats.found += paths.len();
+        listed.push((directory, directory_path, paths));
+    }
+
+    let mut stale = if args.no_prune {
+        StaleFiles::default()
+    } else {
+        let roots: Vec<&Path> = listed.iter().map(|(_, root, _)| root.as_path()).collect();
+        let found_paths: HashSet<String> = listed
+            .iter()
+            .flat_map(|(_, _, paths)| paths.iter().map(|p| p.display().to_string()))
+            .collect();
+        StaleFiles::collect(&known_files, &roots, &found_paths)
+    };
+
+    for (directory, _, paths) in listed {
+        let changed_paths =
+            changed_paths(&mut database, &known_files, paths, args.force, args.hash, &mut stats).await;
+
+        if !changed_paths.is_empty() {
+            pb.set_message(format!("Reading {} changed files from {}", changed_paths.len(), directory));
+        }
+
+        let tracks = scanner.scan_paths(&changed_paths, args.hash).await?;
+        reconcile_tracks(&mut database, &tracks, &known_files, &mut stale, &mut stats).await?;
+
         // Process tracks in batches for better performance
         let batch_size = 50;
         for chunk in tracks.chunks(batch_size) {
-            for track in chunk {
-                match database.add_track(&track).await {
-                    Ok(_) => total_tracks += 1,
-                    Err(e) => {
-                        tracing::warn!("Failed to add track {}: {}", track.file_path, e);
-                    }
-                }
-            }
-            pb.set_message(ProgressMessages::processed_count(total_tracks));
+            stats.read += database.add_tracks(chunk).await?;
+            stats.embedded_lyrics += chunk
+                .iter()
+                .filter(|track| {
+                    track.lrc_lyrics_source == Some(LyricsSource::Embedded)
+                        || track.txt_lyrics_source == Some(LyricsSource::Embedded)
+                })
+                .count();
+            pb.set_message(ProgressMessages::processed_count(stats.read));
         }
     }
 
+    let stale_paths = stale.into_paths();
+    if !stale_paths.is_empty() {
+        pb.set_message(format!("Pruning {} missing files", stale_paths.len()));
+        stats.removed += database.remove_tracks(&stale_paths).await?;
+    }
+
     pb.finish_with_message(ProgressMessages::COMPLETED);
     
     println!("\n🎵 Scan Complete!");
     println!("  📁 Directories scanned: {}", num_directories);
-    println!("  🎶 Tracks found: {}", total_tracks);
+    println!("  🎶 Tracks found: {}", stats.found);
+    println!("  ➕ Added: {}", stats.added);
+    println!("  🔄 Updated: {}", stats.updated);
+    println!("  🚚 Moved: {}", stats.moved);
+    println!("  🗑️  Removed: {}", stats.removed);
+    println!("  ⏭️  Unchanged (skipped): {}", stats.unchanged);
+    println!("  🏷️  Tracks with embedded lyrics: {}", stats.embedded_lyrics);
     
-    if total_tracks > 0 {
+    if stats.found > 0 {
         println!("\n📋 Next steps:");
         println!("  • Run 'lrcget download --missing-lyrics' to download lyrics");
         println!("  • Run 'lrcget config show' to view configuration");
@@ -93,6 +145,182 @@ pub async fn execute(args: ScanArgs, services: &SimpleServices) -> Result<()> {
     Ok(())
 }
 
+#[derive(Default)]
+struct ScanStats {
+    found: usize,
+    read: usize,
+    added: usize,
+    updated: usize,
+    moved: usize,
+    removed: usize,
+    unchanged: usize,
+    embedded_lyrics: usize,
+}
+
+/// Known files below the scanned roots that were not found again, indexed by size
+#[derive(Default)]
+struct StaleFiles {
+    by_size: HashMap<i64, Vec<String>>,
+    /// Rows scanned before fingerprints existed: pruned, but never matched as moves
+    unknown_size: Vec<String>,
+}
+
+impl StaleFiles {
+    fn collect(known_files: &HashMap<String, StoredFileState>, roots: &[&Path], found_paths: &HashSet<String>) -> Self {
+        let mut stale = StaleFiles::default();
+
+        for (known, state) in known_files {
+            let below_root = roots.iter().any(|root| Path::new(known).starts_with(root));
+            if !below_root || found_paths.contains(known) {
+                continue;
+            }
+
+            match &state.fingerprint {
+                Some(fingerprint) => stale.by_size.entry(fingerprint.size).or_default().push(known.clone()),
+                None => stale.unknown_size.push(known.clone()),
+            }
+        }
+
+        stale
+    }
+
+    fn with_size(&self, size: i64) -> &[String] {
+        self.by_size.get(&size).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    /// Stop treating a path as stale once it has been matched to a move
+    fn remove(&mut self, size: i64, path: &str) {
+        if let Some(paths) = self.by_size.get_mut(&size) {
+            paths.retain(|p| p != path);
+        }
+    }
+
+    fn into_paths(self) -> Vec<String> {
+        self.by_size.into_values().flatten().chain(self.unknown_size).collect()
+    }
+}
+
+/// Count freshly read tracks as updated, added or moved. A move points the old row at the
+/// new path, so its id and download history are kept; its lyrics are whatever the new
+/// location has, as sidecars left behind are not moved.
+async fn reconcile_tracks(
+    database: &mut Database,
+    tracks: &[Track],
+    known_files: &HashMap<String, StoredFileState>,
+    stale: &mut StaleFiles,
+    stats: &mut ScanStats,
+) -> Result<()> {
+    for track in tracks {
+        if known_files.contains_key(&track.file_path) {
+            stats.updated += 1;
+            continue;
+        }
+
+        match find_moved_from(database, track, stale).await {
+            Some(old_track) => {
+                database.move_track(&old_track.file_path, &track.file_path).await?;
+                if let Some(fingerprint) = &track.fingerprint {
+                    stale.remove(fingerprint.size, &old_track.file_path);
+                }
+                info!("Detected move: {} -> {}", old_track.file_path, track.file_path);
+                stats.moved += 1;
+            }
+            None => stats.added += 1,
+        }
+    }
+
+    Ok(())
+}
+
+/// Find a vanished track with the same file size and tags as a newly found file
+async fn find_moved_from(database: &Database, track: &Track, stale: &StaleFiles) -> Option<DatabaseTrack> {
+    let size = track.fingerprint.as_ref()?.size;
+
+    for stale_path in stale.with_size(size) {
+        let Ok(old_track) = database.get_track_by_path(stale_path).await else { continue };
+        if old_track.title == track.title
+            && old_track.artist_name == track.artist
+            && old_track.album_name == track.album
+        {
+            return Some(old_track);
+        }
+    }
+
+    None
+}
+
+/// Files that need to be parsed again: new files, files whose fingerprint changed,
+/// or every file with `force`
+async fn changed_paths(
+    database: &mut Database,
+    known_files: &HashMap<String, StoredFileState>,
+    paths: Vec<PathBuf>,
+    force: bool,
+    use_hash: bool,
+    stats: &mut ScanStats,
+) -> Vec<PathBuf> {
+    let mut changed = Vec::new();
+    for path in paths {
+        let file_path = path.display().to_string();
+        let current = FileFingerprint::from_path(&path);
+
+        let unchanged = match (known_files.get(&file_path), &current) {
+            (Some(stored), Some(current)) if !force => {
+                is_unchanged(database, &file_path, &path, stored, current, use_hash).await
+            }
+            _ => false,
+        };
+
+        if unchanged {
+            stats.unchanged += 1;
+        } else {
+            changed.push(path);
+        }
+    }
+    changed
+}
+
+/// Whether a known file can be skipped. With `use_hash`, a file whose timestamp changed
+/// but whose contents hash to the stored value only gets its fingerprint refreshed.
+async fn is_unchanged(
+    database: &mut Database,
+    file_path: &str,
+    path: &Path,
+    stored: &StoredFileState,
+    current: &FileFingerprint,
+    use_hash: bool,
+) -> bool {
+    let Some(ref stored_fingerprint) = stored.fingerprint else {
+        return false;
+    };
+
+    if stored_fingerprint == current {
+        return true;
+    }
+
+    if !use_hash || stored_fingerprint.size != current.size || stored_fingerprint.sidecars != current.sidecars {
+        return false;
+    }
+
+    let Some(ref stored_hash) = stored.content_hash else {
+        return false;
+    };
+
+    match compute_content_hash(path) {
+        Ok(hash) if &hash == stored_hash => {
+            if let Err(e) = database.update_file_fingerprint(file_path, current).await {
+                warn!("Failed to update fingerprint for {}: {}", file_path, e);
+            }
+            true
+        }
+        Ok(_) => false,
+        Err(e) => {
+            debug!("Failed to hash {}: {}", file_path, e);
+            false
+        }
+    }
+}
+
 impl SimpleServices {
     /*...*/
     pub async fn get_directories(&self) -> Result<Vec<String>> {
@@ -101,3 +329,139 @@ impl SimpleServices {
     }
     /*...*/
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::data::database::{DownloadAttempt, DownloadOutcome};
+    use crate::utils::test_dir::TestDir;
+
+    fn track(file_path: &str, size: i64, title: &str) -> Track {
+        Track {
+            file_path: file_path.to_string(),
+            file_name: file_path.rsplit('/').next().unwrap().to_string(),
+            title: title.to_string(),
+            album: "Album".to_string(),
+            artist: "Artist".to_string(),
+            album_artist: "Artist".to_string(),
+            duration: 180.0,
+            txt_lyrics: None,
+            lrc_lyrics: None,
+            track_number: None,
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            fingerprint: Some(FileFingerprint { mtime: 1, size, sidecars: "lrc=;txt=".to_string() }),
+            content_hash: None,
+            instrumental: false,
+        }
+    }
+
+    fn test_file(contents: &[u8]) -> (TestDir, PathBuf) {
+        let dir = TestDir::new("scan");
+        let path = dir.path().join("song.mp3");
+        std::fs::write(&path, contents).unwrap();
+        (dir, path)
+    }
+
+    async fn database_with_file(path: &Path, with_hash: bool) -> Database {
+        let mut database = Database::new(Path::new(":memory:")).await.unwrap();
+        let mut known = track(&path.display().to_string(), 0, "Song");
+        known.fingerprint = FileFingerprint::from_path(path);
+        if with_hash {
+            known.content_hash = Some(compute_content_hash(path).unwrap());
+        }
+        database.add_tracks(&[known]).await.unwrap();
+        database
+    }
+
+    #[tokio::test]
+    async fn test_unchanged_files_are_skipped_unless_forced() {
+        let (_dir, path) = test_file(b"audio");
+        let mut database = database_with_file(&path, false).await;
+        let known_files = database.get_file_states().await.unwrap();
+
+        let mut stats = ScanStats::default();
+        let changed = changed_paths(&mut database, &known_files, vec![path.clone()], false, false, &mut stats).await;
+        assert!(changed.is_empty());
+        assert_eq!(stats.unchanged, 1);
+
+        let changed = changed_paths(&mut database, &known_files, vec![path.clone()], true, false, &mut stats).await;
+        assert_eq!(changed, vec![path.clone()]);
+
+        std::fs::write(&path, b"new audio").unwrap();
+        let changed = changed_paths(&mut database, &known_files, vec![path.clone()], false, false, &mut stats).await;
+        assert_eq!(changed, vec![path.clone()]);
+    }
+
+    #[tokio::test]
+    async fn test_touched_file_is_reread_unless_hash_matches() {
+        let (_dir, path) = test_file(b"audio");
+        let mut database = database_with_file(&path, true).await;
+        let known_files = database.get_file_states().await.unwrap();
+
+        let later = std::time::SystemTime::now() + std::time::Duration::from_secs(60);
+        std::fs::File::options().write(true).open(&path).unwrap().set_modified(later).unwrap();
+
+        let mut stats = ScanStats::default();
+        let changed = changed_paths(&mut database, &known_files, vec![path.clone()], false, false, &mut stats).await;
+        assert_eq!(changed, vec![path.clone()]);
+
+        // Same contents: skipped, and the new timestamp is stored for the next scan
+        let changed = changed_paths(&mut database, &known_files, vec![path.clone()], false, true, &mut stats).await;
+        assert!(changed.is_empty());
+        let stored = database.get_file_states().await.unwrap().remove(&path.display().to_string()).unwrap();
+        assert_eq!(stored.fingerprint, FileFingerprint::from_path(&path));
+    }
+
+    #[tokio::test]
+    async fn test_reconcile_detects_moves_across_roots_and_prunes() {
+        let mut database = Database::new(Path::new(":memory:")).await.unwrap();
+
+        let mut moved = track("/music/a/one.mp3", 100, "One");
+        moved.lrc_lyrics = Some("[00:01.00]One".to_string());
+        moved.lrc_lyrics_source = Some(LyricsSource::Sidecar);
+        database.add_tracks(&[
+            moved,
+            track("/music/a/two.mp3", 200, "Two"),
+            track("/music/a/gone.mp3", 300, "Gone"),
+            track("/elsewhere/keep.mp3", 100, "One"),
+        ]).await.unwrap();
+
+        let moved_id = database.get_track_by_path("/music/a/one.mp3").await.unwrap().id;
+        database.record_download_attempt(&DownloadAttempt {
+            track_id: moved_id,
+            outcome: DownloadOutcome::Synced,
+            source: Some("API".to_string()),
+            lrclib_id: Some(42),
+            error: None,
+            confidence: None,
+        }).await.unwrap();
+
+        let known_files = database.get_file_states().await.unwrap();
+        let found: HashSet<String> = ["/music/a/two.mp3", "/music/b/one.mp3", "/music/b/new.mp3"]
+            .iter().map(|p| p.to_string()).collect();
+        let mut stale = StaleFiles::collect(&known_files, &[Path::new("/music/a"), Path::new("/music/b")], &found);
+
+        let tracks = vec![
+            track("/music/a/two.mp3", 200, "Two"),
+            track("/music/b/one.mp3", 100, "One"),
+            track("/music/b/new.mp3", 100, "New"),
+        ];
+        let mut stats = ScanStats::default();
+        reconcile_tracks(&mut database, &tracks, &known_files, &mut stale, &mut stats).await.unwrap();
+        database.add_tracks(&tracks).await.unwrap();
+        stats.removed += database.remove_tracks(&stale.into_paths()).await.unwrap();
+
+        assert_eq!((stats.updated, stats.moved, stats.added, stats.removed), (1, 1, 1, 1));
+
+        // The moved row keeps its id and download history; the sidecar stayed behind
+        let moved = database.get_track_by_path("/music/b/one.mp3").await.unwrap();
+        assert_eq!(moved.id, moved_id);
+        assert_eq!(moved.lrc_lyrics, None);
+        let status = database.get_download_statuses().await.unwrap().remove(&moved_id).unwrap();
+        assert_eq!(status.lrclib_id, Some(42));
+
+        assert!(database.get_track_by_path("/music/a/gone.mp3").await.is_err());
+        assert!(database.get_track_by_path("/elsewhere/keep.mp3").await.is_ok());
+    }
+}
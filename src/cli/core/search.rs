@@ -1,6 +1,7 @@
 use clap::Args;
 use tracing::info;
 
+use crate::cli::core::download::record_download_result;
 use crate::error::Result;
 use crate::services::SimpleServices;
 
@@ -49,6 +50,10 @@ pub struct SearchArgs {
     /// Apply search results to matching tracks in database
     #[arg(long)]
     apply_to_matches: bool,
+
+    /// Browse results interactively and preview the timing of synced lyrics
+    #[arg(long)]
+    preview: bool,
 }
 
 pub async fn execute(args: SearchArgs, services: &SimpleServices) -> Result<()> {
@@ -95,6 +100,10 @@ pub async fn execute(args: SearchArgs, services: &SimpleServices) -> Result<()>
         return handle_apply_to_matches(&results, &args, services).await;
     }
 
+    if args.preview {
+        return handle_preview(&results, &args);
+    }
+
     // Output results in the specified format
     match args.format.as_str() {
         "json" => output_json(&results)?,
@@ -105,48 +114,81 @@ pub async fn execute(args: SearchArgs, services: &SimpleServices) -> Result<()>
     Ok(())
 }
 
-async fn handle_apply_to_track(track_id: i64, results: &[crate::core::services::lrclib::SearchResult], _services: &SimpleServices) -> Result<()> {
+async fn handle_apply_to_track(track_id: i64, results: &[crate::core::services::lrclib::SearchResult], services: &SimpleServices) -> Result<()> {
     if results.is_empty() {
         return Err(crate::error::LrcGetError::Validation("No search results to apply".to_string()));
     }
 
-    println!("Applying lyrics to track ID: {}", track_id);
-    println!("Available options:");
+    let mut db = services.create_database().await?;
+    let track = db.get_track(track_id).await
+        .map_err(|_| crate::error::LrcGetError::Validation(format!("Track with ID {} not found", track_id)))?;
 
-    for (i, result) in results.iter().enumerate() {
-        println!("  {}. {} - {} ({})",
-            i + 1,
-            result.artist_name.as_deref().unwrap_or("Unknown"),
-            result.name.as_deref().unwrap_or("Unknown"),
-            result.album_name.as_deref().unwrap_or("Unknown")
+    let selected = if crate::ui::tui::is_interactive() {
+        let header = format!(
+            "Apply lyrics to #{}: {} - {} ({})",
+            track.id, track.artist_name, track.title, track.album_name
         );
+        match crate::ui::picker::pick_search_result(results, &header, "apply")? {
+            Some(index) => &results[index],
+            None => {
+                println!("No lyrics applied to track {}", track_id);
+                return Ok(());
+            }
+        }
+    } else {
+        // Without a terminal to prompt on, fall back to the closest match
+        find_best_match(&track, results).ok_or_else(|| {
+            crate::error::LrcGetError::Validation(format!(
+                "No suitable match for track {} in search results", track_id
+            ))
+        })?
+    };
+
+    println!("Applying lyrics from: {} - {} ({})",
+        selected.artist_name.as_deref().unwrap_or("Unknown"),
+        selected.name.as_deref().unwrap_or("Unknown"),
+        selected.album_name.as_deref().unwrap_or("Unknown")
+    );
+
+    // Saved like a download, so the storage policy and download status apply as well
+    let download = services.create_downloader().apply_search_result(&track, selected).await;
+    record_download_result(&mut db, &track, &download).await;
+
+    if download.map_err(crate::error::LrcGetError::Internal)?.rejected {
+        return Err(crate::error::LrcGetError::Validation(format!(
+            "Lyrics not applied to track {}: their length does not fit the track", track_id
+        )));
     }
 
-    // For now, auto-select the first result (in a real implementation, we'd prompt the user)
-    let selected = &results[0];
+    println!("Lyrics applied successfully to track {}: {} - {}", track_id, track.artist_name, track.title);
 
-    println!("Auto-selecting: {} - {}",
-        selected.artist_name.as_deref().unwrap_or("Unknown"),
-        selected.name.as_deref().unwrap_or("Unknown")
+    Ok(())
+}
+
+fn handle_preview(results: &[crate::core::services::lrclib::SearchResult], args: &SearchArgs) -> Result<()> {
+    if !crate::ui::tui::is_interactive() {
+        return Err(crate::error::LrcGetError::Validation("--preview needs an interactive terminal".to_string()));
+    }
+
+    let header = format!(
+        "{} result(s) for \"{}\" · press p on a result to play its synced lyrics",
+        results.len(), args.title
     );
+    if let Some(index) = crate::ui::picker::pick_search_result(results, &header, "show details")? {
+        output_detailed(&results[index..=index]);
+    }
 
-    // Apply the lyrics (this would need to be implemented in the lyrics manager)
-    println!("Lyrics applied successfully to track {}", track_id);
-    
     Ok(())
 }
 
 async fn handle_apply_to_matches(results: &[crate::core::services::lrclib::SearchResult], args: &SearchArgs, services: &SimpleServices) -> Result<()> {
-    // Database access handled through services
-    use crate::core::files::lyrics::LyricsManager;
-    
     if results.is_empty() {
         println!("No search results to apply to matching tracks");
         return Ok(());
     }
 
     // Connect to database
-    let db = services.create_database().await?;
+    let mut db = services.create_database().await?;
     let mut tracks = db.get_all_tracks().await?;
 
     // Apply title filter if provided
@@ -177,7 +219,7 @@ async fn handle_apply_to_matches(results: &[crate::core::services::lrclib::Searc
 
     println!("Found {} matching track(s) in database:", tracks.len());
     
-    let lyrics_manager = LyricsManager::new();
+    let downloader = services.create_downloader();
     let mut applied_count = 0;
     let mut failed_count = 0;
     
@@ -202,12 +244,13 @@ async fn handle_apply_to_matches(results: &[crate::core::services::lrclib::Searc
             );
 
             // Save the lyrics
-            match lyrics_manager.save_lyrics_for_track(
-                track,
-                search_result.plain_lyrics.as_deref(),
-                search_result.synced_lyrics.as_deref(),
-                search_result.instrumental,
-            ).await {
+            let download = downloader.apply_search_result(track, search_result).await;
+            record_download_result(&mut db, track, &download).await;
+            match download {
+                Ok(result) if result.rejected => {
+                    println!("    Lyrics length does not fit the track, not saved");
+                    failed_count += 1;
+                },
                 Ok(_) => {
                     println!("    Lyrics saved successfully");
                     applied_count += 1;
@@ -3,7 +3,7 @@ use anyhow::Result;
 use tracing::info;
 
 use crate::config::Config;
-use crate::core::infrastructure::cache::{LyricsCache, LyricsCacheInterface};
+use crate::core::infrastructure::cache::LyricsCacheInterface;
 
 #[derive(Args)]
 pub struct CacheArgs {
@@ -27,11 +27,7 @@ enum CacheCommands {
 }
 
 pub async fn execute(args: CacheArgs, config: &Config) -> Result<()> {
-    let cache_dir = config.database_path.parent()
-        .unwrap_or(&config.database_path)
-        .join("cache");
-    
-    let mut cache = LyricsCache::new(cache_dir, config.redis_url.as_deref())?;
+    let mut cache = config.create_lyrics_cache()?;
     
     match args.command {
         CacheCommands::Stats => {
@@ -40,8 +36,10 @@ pub async fn execute(args: CacheArgs, config: &Config) -> Result<()> {
             println!("📊 Cache Statistics");
             println!("══════════════════");
             println!("🗂️  Total Entries: {}", stats.total_entries);
+            println!("🚫 Known Missing: {}", stats.negative_entries);
             println!("📈 Total Requests: {}", stats.total_requests);
             println!("✅ Cache Hits: {}", stats.cache_hits);
+            println!("🚫 Negative Hits: {}", stats.negative_hits);
             println!("📊 Hit Rate: {:.1}%", stats.hit_rate_percent);
             
             if stats.last_cleanup > 0 {
@@ -96,14 +94,13 @@ pub async fn execute(args: CacheArgs, config: &Config) -> Result<()> {
         },
         
         CacheCommands::Info => {
-            let cache_path = config.database_path.parent()
-                .unwrap_or(&config.database_path)
-                .join("cache");
+            let cache_path = config.cache_dir();
             
             println!("ℹ️  Cache Configuration");
             println!("═════════════════════");
             println!("📁 Cache Directory: {}", cache_path.display());
             println!("⏰ Max Age: 7 days");
+            println!("🚫 Not-Found Max Age: {} hours", config.negative_cache_ttl_hours);
             println!("📊 Max Entries: 10,000");
             println!("🔧 Auto Cleanup: Yes");
             
@@ -117,6 +114,7 @@ pub async fn execute(args: CacheArgs, config: &Config) -> Result<()> {
             println!("\n💡 Tips:");
             println!("  • Cache improves performance by storing previous API responses");
             println!("  • Entries expire after 7 days to ensure fresh data");
+            println!("  • Tracks LRCLIB has no lyrics for are remembered for {} hours (negative_cache_ttl_hours)", config.negative_cache_ttl_hours);
             println!("  • Use 'lrcget cache stats' to monitor cache performance");
             println!("  • Use 'lrcget cache cleanup' to remove expired entries");
             println!("  • Use 'lrcget cache clear' to start fresh");
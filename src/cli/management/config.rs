@@ -66,6 +66,15 @@ pub async fn execute(args: ConfigArgs, config: &AppConfig) -> Result<()> {
             println!("  ⏱️  watch_debounce_seconds: {}", config.watch_debounce_seconds);
             println!("  📦 watch_batch_size: {}", config.watch_batch_size);
             println!("  🔗 redis_url: {:?}", config.redis_url);
+            println!("  💾 lyrics_storage: {}", format_lyrics_storage(config));
+            println!("  🔁 retry_schedule_days: {}", format_retry_schedule(config));
+            println!("  📏 duration_mismatch_threshold_seconds: {}", config.duration_mismatch_threshold_seconds);
+            println!("  🚫 duration_mismatch_action: {}", config.duration_mismatch_action);
+            println!("  🚦 lrclib_requests_per_second: {}", config.lrclib_requests_per_second);
+            println!("  🔄 lrclib_max_retries: {}", config.lrclib_max_retries);
+            println!("  🚫 negative_cache_ttl_hours: {}", config.negative_cache_ttl_hours);
+            println!("  ⚡ min_parallel_downloads: {}", config.min_parallel_downloads);
+            println!("  ⚡ max_parallel_downloads: {}", config.max_parallel_downloads);
 
             // Show environment overrides if present
             let env_vars = crate::config::env::EnvParser::get_all_lrcget_vars();
@@ -92,7 +101,16 @@ pub async fn execute(args: ConfigArgs, config: &AppConfig) -> Result<()> {
                 .show_line_count(config.show_line_count)
                 .watch_debounce_seconds(config.watch_debounce_seconds)?
                 .watch_batch_size(config.watch_batch_size)?
-                .redis_url(config.redis_url.clone())?;
+                .redis_url(config.redis_url.clone())?
+                .lyrics_storage(config.lyrics_storage.clone())?
+                .retry_schedule_days(config.retry_schedule_days.clone())?
+                .duration_mismatch_threshold_seconds(config.duration_mismatch_threshold_seconds)?
+                .duration_mismatch_action(config.duration_mismatch_action)
+                .lrclib_requests_per_second(config.lrclib_requests_per_second)?
+                .lrclib_max_retries(config.lrclib_max_retries)?
+                .negative_cache_ttl_hours(config.negative_cache_ttl_hours)?
+                .min_parallel_downloads(config.min_parallel_downloads)?
+                .max_parallel_downloads(config.max_parallel_downloads)?;
 
             // Apply the new value with validation
             match key.as_str() {
@@ -149,6 +167,66 @@ pub async fn execute(args: ConfigArgs, config: &AppConfig) -> Result<()> {
                     };
                     builder = builder.redis_url(url)?;
                 },
+                "lyrics_storage" => {
+                    let spec = if value.to_lowercase() == "none" { "" } else { value.as_str() };
+                    builder = builder.lyrics_storage_spec(spec)?;
+                },
+                "retry_schedule_days" => {
+                    let spec = if value.to_lowercase() == "none" { "" } else { value.as_str() };
+                    builder = builder.retry_schedule_spec(spec)?;
+                },
+                "duration_mismatch_threshold_seconds" => {
+                    let parsed = value.parse::<f64>().map_err(|_| {
+                        crate::error::LrcGetError::Validation(format!(
+                            "Invalid value for {}: '{}'. Must be a number between 1 and 600",
+                            key, value
+                        ))
+                    })?;
+                    builder = builder.duration_mismatch_threshold_seconds(parsed)?;
+                },
+                "duration_mismatch_action" => {
+                    builder = builder.duration_mismatch_action_spec(&value)?;
+                },
+                "lrclib_requests_per_second" => {
+                    let parsed = value.parse::<f64>().map_err(|_| {
+                        crate::error::LrcGetError::Validation(format!(
+                            "Invalid value for {}: '{}'. Must be a number between 0.1 and 100",
+                            key, value
+                        ))
+                    })?;
+                    builder = builder.lrclib_requests_per_second(parsed)?;
+                },
+                "lrclib_max_retries" => {
+                    let parsed = value.parse::<u32>().map_err(|_| {
+                        crate::error::LrcGetError::Validation(format!(
+                            "Invalid value for {}: '{}'. Must be a number between 0 and 10",
+                            key, value
+                        ))
+                    })?;
+                    builder = builder.lrclib_max_retries(parsed)?;
+                },
+                "negative_cache_ttl_hours" => {
+                    let parsed = value.parse::<u64>().map_err(|_| {
+                        crate::error::LrcGetError::Validation(format!(
+                            "Invalid value for {}: '{}'. Must be a number between 1 and 720",
+                            key, value
+                        ))
+                    })?;
+                    builder = builder.negative_cache_ttl_hours(parsed)?;
+                },
+                "min_parallel_downloads" | "max_parallel_downloads" => {
+                    let parsed = value.parse::<usize>().map_err(|_| {
+                        crate::error::LrcGetError::Validation(format!(
+                            "Invalid value for {}: '{}'. Must be a number between 1 and 64",
+                            key, value
+                        ))
+                    })?;
+                    builder = if key == "min_parallel_downloads" {
+                        builder.min_parallel_downloads(parsed)?
+                    } else {
+                        builder.max_parallel_downloads(parsed)?
+                    };
+                },
                 _ => {
                     return Err(crate::error::LrcGetError::Validation(format!(
                         "Unknown configuration key: '{}'. Use 'lrcget config keys' to see available keys",
@@ -179,6 +257,15 @@ pub async fn execute(args: ConfigArgs, config: &AppConfig) -> Result<()> {
                 "redis_url" => config.redis_url.as_ref()
                     .map(|url| url.clone())
                     .unwrap_or_else(|| "none".to_string()),
+                "lyrics_storage" => format_lyrics_storage(config),
+                "retry_schedule_days" => format_retry_schedule(config),
+                "duration_mismatch_threshold_seconds" => config.duration_mismatch_threshold_seconds.to_string(),
+                "duration_mismatch_action" => config.duration_mismatch_action.to_string(),
+                "lrclib_requests_per_second" => config.lrclib_requests_per_second.to_string(),
+                "lrclib_max_retries" => config.lrclib_max_retries.to_string(),
+                "negative_cache_ttl_hours" => config.negative_cache_ttl_hours.to_string(),
+                "min_parallel_downloads" => config.min_parallel_downloads.to_string(),
+                "max_parallel_downloads" => config.max_parallel_downloads.to_string(),
                 _ => {
                     return Err(crate::error::LrcGetError::Validation(format!(
                         "Unknown configuration key: '{}'. Use 'lrcget config keys' to see available keys",
@@ -210,12 +297,21 @@ pub async fn execute(args: ConfigArgs, config: &AppConfig) -> Result<()> {
             println!("  lrclib_instance                   - LRCLIB server URL (e.g., https://lrclib.net)");
             println!("  🗃️  lrclib_database_path             - Local LRCLIB database path (optional)");
             println!("  🔗 redis_url                       - Redis cache URL (optional)");
+            println!("  🚦 lrclib_requests_per_second      - Requests per second to LRCLIB across all downloads (0.1-100)");
+            println!("  🔄 lrclib_max_retries              - Retries after a 429, 5xx or network error (0-10)");
+            println!("  🚫 negative_cache_ttl_hours        - Hours a not-found result stays cached (1-720)");
+            println!("  ⚡ min_parallel_downloads          - Fewest parallel downloads when LRCLIB is slow (1-64)");
+            println!("  ⚡ max_parallel_downloads          - Most parallel downloads when LRCLIB keeps up (1-64)");
             println!();
             println!("🎵 Lyrics Processing:");
             println!("  ⏭️  skip_tracks_with_synced_lyrics   - Skip tracks that already have synced lyrics");
             println!("  📝 skip_tracks_with_plain_lyrics    - Skip tracks that already have plain lyrics");
             println!("  🎵 try_embed_lyrics                 - Embed lyrics into audio files");
             println!("  📊 show_line_count                  - Show line count in lyrics");
+            println!("  💾 lyrics_storage                   - Per-format storage when embedding (e.g., m4a=embed,flac=both)");
+            println!("  🔁 retry_schedule_days              - Days before re-checking not-found tracks (e.g., 1,7,30)");
+            println!("  📏 duration_mismatch_threshold_seconds - Allowed gap between lyrics and track length (1-600)");
+            println!("  🚫 duration_mismatch_action         - Flag or reject lyrics that do not fit the track length");
             println!();
             println!("⚙️  Watch Mode:");
             println!("  ⏱️  watch_debounce_seconds          - Debounce time for file changes (1-3600)");
@@ -352,3 +448,28 @@ fn parse_bool_value(value: &str) -> Result<bool> {
         )))
     }
 }
+
+/// Format per-format lyrics storage as the `format=mode,...` list accepted by `config set`
+fn format_lyrics_storage(config: &AppConfig) -> String {
+    if config.lyrics_storage.is_empty() {
+        return "none".to_string();
+    }
+
+    config.lyrics_storage
+        .iter()
+        .map(|(format, mode)| format!("{}={}", format, mode))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+fn format_retry_schedule(config: &AppConfig) -> String {
+    if config.retry_schedule_days.is_empty() {
+        return "none".to_string();
+    }
+
+    config.retry_schedule_days
+        .iter()
+        .map(|days| days.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
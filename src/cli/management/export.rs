@@ -10,7 +10,7 @@ use crate::core::data::database::{Database, DatabaseTrack};
 
 #[derive(Args)]
 pub struct ExportArgs {
-    /// Output format (json, csv, xml, toml, m3u, txt)
+    /// Output format (json, csv, xml, toml, m3u, m3u8, xspf, txt)
     #[arg(short, long, default_value = "json")]
     format: String,
 
@@ -18,7 +18,8 @@ pub struct ExportArgs {
     #[arg(short, long)]
     output: Option<String>,
 
-    /// Export type (library, config, missing-lyrics, stats)
+    /// Export type (library, config, missing-lyrics, stats). As a playlist (m3u, m3u8, xspf),
+    /// missing-lyrics lists every track lacking synced lyrics
     #[arg(short, long, default_value = "library")]
     export_type: String,
 
@@ -52,6 +53,8 @@ struct ExportMetadata {
     version: String,
     total_tracks: usize,
     tracks_with_lyrics: usize,
+    #[serde(default)]
+    tracks_instrumental: usize,
     tracks_missing_lyrics: usize,
 }
 
@@ -64,12 +67,44 @@ struct ExportTrack {
     duration: f64,
     has_synced_lyrics: bool,
     has_plain_lyrics: bool,
+    #[serde(default)]
+    instrumental: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     synced_lyrics: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     plain_lyrics: Option<String>,
 }
 
+impl ExportTrack {
+    fn from_track(track: &DatabaseTrack, include_lyrics: bool) -> Self {
+        ExportTrack {
+            file_path: track.file_path.clone(),
+            title: track.title.clone(),
+            artist: track.artist_name.clone(),
+            album: track.album_name.clone(),
+            duration: track.duration,
+            has_synced_lyrics: track.lrc_lyrics.is_some(),
+            has_plain_lyrics: track.txt_lyrics.is_some(),
+            instrumental: track.instrumental,
+            synced_lyrics: if include_lyrics { track.lrc_lyrics.clone() } else { None },
+            plain_lyrics: if include_lyrics { track.txt_lyrics.clone() } else { None },
+        }
+    }
+
+    /// synced, plain, instrumental or missing
+    fn lyrics_status(&self) -> &'static str {
+        if self.has_synced_lyrics {
+            "synced"
+        } else if self.has_plain_lyrics {
+            "plain"
+        } else if self.instrumental {
+            "instrumental"
+        } else {
+            "missing"
+        }
+    }
+}
+
 pub async fn execute(args: ExportArgs, config: &Config) -> Result<()> {
     match args.export_type.as_str() {
         "library" => export_library(&args, config).await,
@@ -95,23 +130,15 @@ async fn export_library(args: &ExportArgs, config: &Config) -> Result<()> {
         tracks.retain(|t| t.album_name.to_lowercase().contains(&album.to_lowercase()));
     }
 
-    let export_tracks: Vec<ExportTrack> = tracks.iter().map(|track| {
-        ExportTrack {
-            file_path: track.file_path.clone(),
-            title: track.title.clone(),
-            artist: track.artist_name.clone(),
-            album: track.album_name.clone(),
-            duration: track.duration,
-            has_synced_lyrics: track.lrc_lyrics.is_some(),
-            has_plain_lyrics: track.txt_lyrics.is_some(),
-            synced_lyrics: if args.include_lyrics { track.lrc_lyrics.clone() } else { None },
-            plain_lyrics: if args.include_lyrics { track.txt_lyrics.clone() } else { None },
-        }
-    }).collect();
+    let export_tracks: Vec<ExportTrack> = tracks.iter()
+        .map(|track| ExportTrack::from_track(track, args.include_lyrics))
+        .collect();
 
     let tracks_with_lyrics = export_tracks.iter()
         .filter(|t| t.has_synced_lyrics || t.has_plain_lyrics)
         .count();
+    let tracks_instrumental = tracks.iter().filter(|t| t.instrumental).count();
+    let tracks_missing_lyrics = tracks.iter().filter(|t| t.is_missing_lyrics()).count();
 
     let export_data = LibraryExport {
         metadata: ExportMetadata {
@@ -120,7 +147,8 @@ async fn export_library(args: &ExportArgs, config: &Config) -> Result<()> {
             version: env!("CARGO_PKG_VERSION").to_string(),
             total_tracks: export_tracks.len(),
             tracks_with_lyrics,
-            tracks_missing_lyrics: export_tracks.len() - tracks_with_lyrics,
+            tracks_instrumental,
+            tracks_missing_lyrics,
         },
         tracks: export_tracks,
     };
@@ -136,9 +164,10 @@ async fn export_library(args: &ExportArgs, config: &Config) -> Result<()> {
         "csv" => export_to_csv(&export_data)?,
         "xml" => export_to_xml(&export_data)?,
         "toml" => toml::to_string_pretty(&export_data)?,
-        "m3u" => export_to_m3u(&export_data),
+        "m3u" | "m3u8" => export_to_m3u(&export_data.tracks),
+        "xspf" => export_to_xspf(&export_data.tracks, "lrcget library"),
         "txt" => export_to_txt(&export_data),
-        _ => anyhow::bail!("Unsupported format: {}. Available: json, csv, xml, toml, m3u, txt", args.format),
+        _ => anyhow::bail!("Unsupported format: {}. Available: json, csv, xml, toml, m3u, m3u8, xspf, txt", args.format),
     };
 
     if let Some(output_path) = &args.output {
@@ -183,9 +212,21 @@ async fn export_missing_lyrics(args: &ExportArgs, config: &Config) -> Result<()>
     
     let db = Database::new(&config.database_path).await?;
     let tracks = db.get_all_tracks().await?;
+
+    if matches!(args.format.as_str(), "m3u" | "m3u8" | "xspf") {
+        let lacking_synced = lacking_synced_lyrics(&tracks);
+
+        info!("Found {} tracks lacking synced lyrics", lacking_synced.len());
+
+        let output = match args.format.as_str() {
+            "xspf" => export_to_xspf(&lacking_synced, "Tracks lacking synced lyrics"),
+            _ => export_to_m3u(&lacking_synced),
+        };
+        return write_output(args, &output, &format!("{} tracks lacking synced lyrics", lacking_synced.len()));
+    }
     
     let missing_tracks: Vec<&DatabaseTrack> = tracks.iter()
-        .filter(|t| t.lrc_lyrics.is_none() && t.txt_lyrics.is_none())
+        .filter(|t| t.is_missing_lyrics())
         .collect();
 
     info!("Found {} tracks missing lyrics", missing_tracks.len());
@@ -213,7 +254,7 @@ async fn export_missing_lyrics(args: &ExportArgs, config: &Config) -> Result<()>
             }
             txt
         },
-        _ => anyhow::bail!("Missing lyrics export supports json, csv, and txt formats"),
+        _ => anyhow::bail!("Missing lyrics export supports json, csv, txt, m3u, m3u8, and xspf formats"),
     };
 
     if let Some(output_path) = &args.output {
@@ -227,6 +268,15 @@ async fn export_missing_lyrics(args: &ExportArgs, config: &Config) -> Result<()>
     Ok(())
 }
 
+/// Tracks for the missing-lyrics playlist. Playlists are for spot-checking in a player,
+/// so plain-only tracks belong in them too
+fn lacking_synced_lyrics(tracks: &[DatabaseTrack]) -> Vec<ExportTrack> {
+    tracks.iter()
+        .filter(|t| t.lrc_lyrics.is_none() && !t.instrumental)
+        .map(|t| ExportTrack::from_track(t, false))
+        .collect()
+}
+
 async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
     info!("📈 Generating statistics...");
     
@@ -239,6 +289,7 @@ async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
         tracks_with_synced_lyrics: usize,
         tracks_with_plain_lyrics: usize,
         tracks_with_any_lyrics: usize,
+        tracks_instrumental: usize,
         tracks_missing_lyrics: usize,
         coverage_percentage: f64,
         artists_count: usize,
@@ -250,8 +301,9 @@ async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
     let synced_count = tracks.iter().filter(|t| t.lrc_lyrics.is_some()).count();
     let plain_count = tracks.iter().filter(|t| t.txt_lyrics.is_some()).count();
     let any_lyrics_count = tracks.iter().filter(|t| t.lrc_lyrics.is_some() || t.txt_lyrics.is_some()).count();
-    let missing_count = tracks.len() - any_lyrics_count;
-    let coverage = if tracks.len() > 0 { (any_lyrics_count as f64 / tracks.len() as f64) * 100.0 } else { 0.0 };
+    let instrumental_count = tracks.iter().filter(|t| t.instrumental).count();
+    let missing_count = tracks.iter().filter(|t| t.is_missing_lyrics()).count();
+    let coverage = if tracks.len() > 0 { ((tracks.len() - missing_count) as f64 / tracks.len() as f64) * 100.0 } else { 0.0 };
     
     let unique_artists: std::collections::HashSet<_> = tracks.iter().map(|t| &t.artist_name).collect();
     let unique_albums: std::collections::HashSet<_> = tracks.iter().map(|t| &t.album_name).collect();
@@ -265,6 +317,7 @@ async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
         tracks_with_synced_lyrics: synced_count,
         tracks_with_plain_lyrics: plain_count,
         tracks_with_any_lyrics: any_lyrics_count,
+        tracks_instrumental: instrumental_count,
         tracks_missing_lyrics: missing_count,
         coverage_percentage: coverage,
         artists_count: unique_artists.len(),
@@ -295,6 +348,7 @@ async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
                 🎼 Synced Lyrics: {}\n\
                 📄 Plain Lyrics: {}\n\
                 ✅ Any Lyrics: {}\n\
+                🎻 Instrumental: {}\n\
                 ❌ Missing Lyrics: {}\n\
                 📈 Coverage: {:.1}%\n",
                 stats.total_tracks,
@@ -304,6 +358,7 @@ async fn export_stats(args: &ExportArgs, config: &Config) -> Result<()> {
                 stats.tracks_with_synced_lyrics,
                 stats.tracks_with_plain_lyrics,
                 stats.tracks_with_any_lyrics,
+                stats.tracks_instrumental,
                 stats.tracks_missing_lyrics,
                 stats.coverage_percentage
             )
@@ -369,21 +424,81 @@ fn export_to_xml(data: &LibraryExport) -> Result<String> {
     Ok(xml)
 }
 
-fn export_to_m3u(data: &LibraryExport) -> String {
+/// Extended M3U: `#EXTINF` with length and `Artist - Title`, `#EXTALB`, and a comment
+/// with the lyrics status that players ignore
+fn export_to_m3u(tracks: &[ExportTrack]) -> String {
     let mut m3u = "#EXTM3U\n".to_string();
     
-    for track in &data.tracks {
+    for track in tracks {
         m3u.push_str(&format!("#EXTINF:{},{} - {}\n", 
-            track.duration as i32, 
+            playlist_seconds(track.duration), 
             track.artist, 
             track.title
         ));
+        if !track.album.is_empty() {
+            m3u.push_str(&format!("#EXTALB:{}\n", track.album));
+        }
+        m3u.push_str(&format!("# lyrics: {}\n", track.lyrics_status()));
         m3u.push_str(&format!("{}\n", track.file_path));
     }
     
     m3u
 }
 
+/// XSPF playlist; each track's lyrics status goes in its `<annotation>`
+fn export_to_xspf(tracks: &[ExportTrack], title: &str) -> String {
+    let mut xspf = String::new();
+    xspf.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    xspf.push_str("<playlist version=\"1\" xmlns=\"http://xspf.org/ns/0/\">\n");
+    xspf.push_str(&format!("  <title>{}</title>\n", escape_xml(title)));
+    xspf.push_str("  <creator>lrcget-cli</creator>\n");
+    xspf.push_str(&format!("  <date>{}</date>\n", chrono::Utc::now().to_rfc3339()));
+    xspf.push_str("  <trackList>\n");
+
+    for track in tracks {
+        xspf.push_str("    <track>\n");
+        xspf.push_str(&format!("      <location>{}</location>\n", escape_xml(&file_location(&track.file_path))));
+        xspf.push_str(&format!("      <title>{}</title>\n", escape_xml(&track.title)));
+        xspf.push_str(&format!("      <creator>{}</creator>\n", escape_xml(&track.artist)));
+        if !track.album.is_empty() {
+            xspf.push_str(&format!("      <album>{}</album>\n", escape_xml(&track.album)));
+        }
+        if track.duration > 0.0 {
+            xspf.push_str(&format!("      <duration>{}</duration>\n", (track.duration * 1000.0).round() as u64));
+        }
+        xspf.push_str(&format!("      <annotation>Lyrics: {}</annotation>\n", track.lyrics_status()));
+        xspf.push_str("    </track>\n");
+    }
+
+    xspf.push_str("  </trackList>\n");
+    xspf.push_str("</playlist>\n");
+    xspf
+}
+
+/// Whole seconds for `#EXTINF`, where -1 means unknown
+fn playlist_seconds(duration: f64) -> i64 {
+    if duration > 0.0 { duration.round() as i64 } else { -1 }
+}
+
+/// `file://` URI for absolute paths; relative paths are kept as relative URIs
+fn file_location(file_path: &str) -> String {
+    match url::Url::from_file_path(file_path) {
+        Ok(url) => url.to_string(),
+        Err(()) => file_path.to_string(),
+    }
+}
+
+fn write_output(args: &ExportArgs, output: &str, description: &str) -> Result<()> {
+    if let Some(output_path) = &args.output {
+        let mut file = File::create(output_path)?;
+        file.write_all(output.as_bytes())?;
+        info!("✅ Exported {} to {}", description, output_path);
+    } else {
+        println!("{}", output);
+    }
+    Ok(())
+}
+
 fn export_to_txt(data: &LibraryExport) -> String {
     let mut txt = format!("Library Export - {}\n", data.metadata.exported_at);
     txt.push_str("═══════════════════════════════════════\n\n");
@@ -417,4 +532,71 @@ fn escape_xml(s: &str) -> String {
         .replace(">", "&gt;")
         .replace("\"", "&quot;")
         .replace("'", "&apos;")
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(title: &str, file_path: &str, duration: f64) -> DatabaseTrack {
+        DatabaseTrack {
+            id: 1,
+            file_path: file_path.to_string(),
+            file_name: "song.mp3".to_string(),
+            title: title.to_string(),
+            album_name: "Album".to_string(),
+            artist_name: "Artist".to_string(),
+            album_artist: "Artist".to_string(),
+            duration,
+            track_number: None,
+            txt_lyrics: None,
+            lrc_lyrics: None,
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            instrumental: false,
+        }
+    }
+
+    #[test]
+    fn test_m3u_uses_minus_one_for_unknown_length() {
+        let tracks = [
+            ExportTrack::from_track(&track("Known", "/music/known.mp3", 181.6), false),
+            ExportTrack::from_track(&track("Unknown", "/music/unknown.mp3", 0.0), false),
+        ];
+        let m3u = export_to_m3u(&tracks);
+        assert!(m3u.starts_with("#EXTM3U\n"));
+        assert!(m3u.contains("#EXTINF:182,Artist - Known\n#EXTALB:Album\n# lyrics: missing\n/music/known.mp3\n"));
+        assert!(m3u.contains("#EXTINF:-1,Artist - Unknown\n"));
+    }
+
+    #[test]
+    fn test_xspf_escapes_text_and_locations() {
+        let mut rock = track("Rock & Roll <Live>", "/music/AC&DC/song one.mp3", 0.0);
+        rock.artist_name = "AC/DC \"Live\"".to_string();
+        let xspf = export_to_xspf(&[ExportTrack::from_track(&rock, false)], "Tom's list");
+
+        assert!(xspf.contains("<title>Tom&apos;s list</title>"));
+        assert!(xspf.contains("<location>file:///music/AC&amp;DC/song%20one.mp3</location>"));
+        assert!(xspf.contains("<title>Rock &amp; Roll &lt;Live&gt;</title>"));
+        assert!(xspf.contains("<creator>AC/DC &quot;Live&quot;</creator>"));
+        // Unknown lengths are left out rather than written as 0
+        assert!(!xspf.contains("<duration>"));
+        assert_eq!(file_location("relative/song.mp3"), "relative/song.mp3");
+    }
+
+    #[test]
+    fn test_missing_lyrics_playlist_includes_plain_only_tracks() {
+        let mut synced = track("Synced", "/music/synced.mp3", 180.0);
+        synced.lrc_lyrics = Some("[00:01.00]Words".to_string());
+        let mut plain = track("Plain", "/music/plain.mp3", 180.0);
+        plain.txt_lyrics = Some("Words".to_string());
+        let mut instrumental = track("Instrumental", "/music/instrumental.mp3", 180.0);
+        instrumental.instrumental = true;
+        let missing = track("Missing", "/music/missing.mp3", 180.0);
+
+        let listed = lacking_synced_lyrics(&[synced, plain, instrumental, missing]);
+        let titles: Vec<&str> = listed.iter().map(|t| t.title.as_str()).collect();
+        assert_eq!(titles, vec!["Plain", "Missing"]);
+        assert_eq!(listed[0].lyrics_status(), "plain");
+    }
+}
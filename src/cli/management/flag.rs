@@ -0,0 +1,143 @@
+use clap::{Args, Subcommand};
+use std::path::PathBuf;
+
+use crate::core::data::lrclib_db::LrclibDatabase;
+use crate::error::{LrcGetError, Result};
+use crate::services::SimpleServices;
+
+#[derive(Args)]
+#[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+pub struct FlagArgs {
+    #[command(subcommand)]
+    command: Option<FlagCommands>,
+
+    /// Library track whose current lyrics are wrong
+    #[arg(value_name = "TRACK_ID", required = true)]
+    track_id: Option<i64>,
+
+    /// What is wrong with the lyrics
+    #[arg(value_name = "REASON", required = true)]
+    reason: Option<String>,
+
+    /// LRCLIB id of the lyrics, instead of the one recorded when they were downloaded
+    #[arg(long, value_name = "ID")]
+    lyrics_id: Option<i64>,
+}
+
+#[derive(Subcommand)]
+enum FlagCommands {
+    /// Show flagged lyrics
+    List,
+
+    /// Remove a flag so the lyrics can be used again
+    Remove {
+        /// Flag id as shown by `lrcget flag list`
+        flag_id: i64,
+    },
+}
+
+pub async fn execute(args: FlagArgs, services: &SimpleServices) -> Result<()> {
+    let lrclib_db = open_lrclib_database(services).await?;
+
+    match args.command {
+        Some(FlagCommands::List) => list_flags(&lrclib_db).await,
+        Some(FlagCommands::Remove { flag_id }) => {
+            if !lrclib_db.remove_flag(flag_id).await? {
+                return Err(LrcGetError::Validation(format!("No flag with id {}", flag_id)));
+            }
+            println!("✅ Removed flag {}", flag_id);
+            Ok(())
+        }
+        None => {
+            // clap requires both when no subcommand is given
+            let (Some(track_id), Some(reason)) = (args.track_id, args.reason) else {
+                return Err(LrcGetError::Validation("Usage: lrcget flag <TRACK_ID> <REASON>".to_string()));
+            };
+            flag_track(services, &lrclib_db, track_id, args.lyrics_id, &reason).await
+        }
+    }
+}
+
+/// Flags live in the local LRCLIB database, next to the lyrics they exclude
+async fn open_lrclib_database(services: &SimpleServices) -> Result<LrclibDatabase> {
+    let config = services.config();
+    let db_path: PathBuf = match &config.lrclib_database_path {
+        Some(path) if path.exists() => path.clone(),
+        Some(path) => {
+            return Err(LrcGetError::Validation(format!(
+                "Local LRCLIB database not found at {}",
+                path.display()
+            )))
+        }
+        None => {
+            return Err(LrcGetError::Validation(
+                "Flags are kept in the local LRCLIB database; set lrclib_database_path first".to_string(),
+            ))
+        }
+    };
+
+    let lrclib_db = LrclibDatabase::new(&db_path).await?;
+    lrclib_db.migrate().await?;
+    Ok(lrclib_db)
+}
+
+async fn flag_track(
+    services: &SimpleServices,
+    lrclib_db: &LrclibDatabase,
+    track_id: i64,
+    lyrics_id: Option<i64>,
+    reason: &str,
+) -> Result<()> {
+    let db = services.create_database().await?;
+    let track = db.get_track(track_id).await.map_err(|_| {
+        LrcGetError::Validation(format!("No track with id {} in the library", track_id))
+    })?;
+
+    // Flags are keyed on the LRCLIB id recorded when the lyrics were downloaded
+    let lyrics_id = match lyrics_id {
+        Some(id) => id,
+        None => db
+            .get_download_status(track_id)
+            .await?
+            .and_then(|status| status.lrclib_id)
+            .ok_or_else(|| {
+                LrcGetError::Validation(format!(
+                    "No LRCLIB id recorded for {} - {}; pass it with --lyrics-id",
+                    track.artist_name, track.title
+                ))
+            })?,
+    };
+
+    let flag_id = lrclib_db.add_flag(lyrics_id, reason).await?;
+    println!("🚩 Flagged the lyrics of {} - {} (flag {}, lyrics {})", track.artist_name, track.title, flag_id, lyrics_id);
+    println!("💡 They will be skipped by future downloads; the files on disk are unchanged");
+
+    Ok(())
+}
+
+async fn list_flags(lrclib_db: &LrclibDatabase) -> Result<()> {
+    let flags = lrclib_db.list_flags().await?;
+
+    if flags.is_empty() {
+        println!("No flagged lyrics");
+        return Ok(());
+    }
+
+    println!("🚩 Flagged lyrics ({})", flags.len());
+    for flag in &flags {
+        let track = match (&flag.artist_name, &flag.track_name) {
+            (Some(artist), Some(title)) => format!("{} - {}", artist, title),
+            _ => "(unknown track)".to_string(),
+        };
+        let album = flag.album_name.as_deref().filter(|album| !album.is_empty());
+        let date = flag.created_at.get(..10).unwrap_or(&flag.created_at);
+
+        match album {
+            Some(album) => println!("  #{:<4} {} [{}]", flag.id, track, album),
+            None => println!("  #{:<4} {}", flag.id, track),
+        }
+        println!("        {} · lyrics {} · {}", flag.reason, flag.lyrics_id, date);
+    }
+
+    Ok(())
+}
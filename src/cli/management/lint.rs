@@ -0,0 +1,137 @@
+use clap::Args;
+use serde::Serialize;
+use std::path::PathBuf;
+use tracing::{info, warn};
+
+use crate::core::files::lrc_lint::{lint_lrc_file, LintIssue, LintSeverity};
+use crate::cli::core::download::refresh_library_track;
+use crate::core::files::scanner::lrc_sidecar_path;
+use crate::error::{LrcGetError, Result};
+use crate::services::SimpleServices;
+
+#[derive(Args)]
+pub struct LintArgs {
+    /// Rewrite files whose problems can be fixed automatically
+    #[arg(long)]
+    fix: bool,
+
+    /// Output format (text, json)
+    #[arg(long, default_value = "text")]
+    format: String,
+}
+
+#[derive(Serialize)]
+struct FileLint {
+    path: PathBuf,
+    track_id: i64,
+    fixed: bool,
+    issues: Vec<LintIssue>,
+}
+
+impl FileLint {
+    /// Issues still present on disk after any fix
+    fn remaining(&self) -> impl Iterator<Item = &LintIssue> {
+        self.issues.iter().filter(move |issue| !(self.fixed && issue.fixable))
+    }
+}
+
+pub async fn execute(args: LintArgs, services: &SimpleServices) -> Result<()> {
+    let mut db = services.create_database().await?;
+    let tracks = db.get_all_tracks().await?;
+
+    info!("🔎 Linting .lrc files for {} tracks", tracks.len());
+
+    let mut checked = 0;
+    let mut reports: Vec<FileLint> = Vec::new();
+
+    for track in &tracks {
+        let path = lrc_sidecar_path(&track.file_path);
+        if !path.exists() {
+            continue;
+        }
+        checked += 1;
+
+        let lint = match lint_lrc_file(&path, Some(track.duration)) {
+            Ok(lint) => lint,
+            Err(e) => {
+                warn!("Failed to read {}: {}", path.display(), e);
+                continue;
+            }
+        };
+        if lint.is_clean() {
+            continue;
+        }
+
+        let mut fixed = false;
+        if let (true, Some(content)) = (args.fix, &lint.fixed) {
+            match std::fs::write(&path, content) {
+                Ok(()) => {
+                    fixed = true;
+                    // Keep the library's copy of the lyrics in step with the file
+                    refresh_library_track(&mut db, track).await;
+                }
+                Err(e) => warn!("Failed to write {}: {}", path.display(), e),
+            }
+        }
+
+        reports.push(FileLint {
+            path,
+            track_id: track.id,
+            fixed,
+            issues: lint.issues,
+        });
+    }
+
+    let with_errors = reports
+        .iter()
+        .filter(|report| report.remaining().any(|issue| issue.kind.severity() == LintSeverity::Error))
+        .count();
+    let fixed = reports.iter().filter(|report| report.fixed).count();
+
+    match args.format.as_str() {
+        "json" => println!("{}", serde_json::to_string_pretty(&reports)?),
+        _ => {
+            output_text(&reports);
+
+            println!("\n📊 Lint Summary:");
+            println!("  📄 Files checked: {}", checked);
+            println!("  ✅ Clean: {}", checked - reports.len());
+            println!("  ❌ With errors: {}", with_errors);
+            println!("  ⚠️ With warnings only: {}", reports.iter().filter(|r| r.remaining().next().is_some()).count() - with_errors);
+            if args.fix {
+                println!("  🔧 Fixed: {}", fixed);
+            } else if reports.iter().any(|r| r.issues.iter().any(|issue| issue.fixable)) {
+                println!("\n💡 Run 'lrcget lint --fix' to rewrite fixable files");
+            }
+        }
+    }
+
+    if with_errors > 0 {
+        return Err(LrcGetError::Validation(format!("{} .lrc file(s) have errors", with_errors)));
+    }
+
+    Ok(())
+}
+
+fn output_text(reports: &[FileLint]) {
+    for report in reports {
+        let icon = if report.remaining().next().is_none() {
+            "🔧"
+        } else if report.remaining().any(|issue| issue.kind.severity() == LintSeverity::Error) {
+            "❌"
+        } else {
+            "⚠️"
+        };
+        println!("{} {}", icon, report.path.display());
+
+        for issue in &report.issues {
+            let location = issue.line.map(|line| format!("line {}: ", line)).unwrap_or_default();
+            let note = match (issue.fixable, report.fixed) {
+                (true, true) => " (fixed)",
+                (true, false) => " (fixable)",
+                _ => "",
+            };
+            println!("   {}{}{}", location, issue.message, note);
+        }
+    }
+}
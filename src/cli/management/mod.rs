@@ -1,12 +1,16 @@
 //! Management and configuration commands
 //!
 //! This module contains commands for managing the application:
-//! configuration, cache, data export, hooks, and templates.
+//! configuration, cache, data export, hooks, templates, linting or shifting lyrics files,
+//! and flagging bad lyrics.
 
 pub mod config;
 pub mod cache;
 pub mod export;
 pub mod hooks;
 pub mod templates;
+pub mod lint;
+pub mod shift;
+pub mod flag;
 
 // Re-export for convenience
@@ -0,0 +1,166 @@
+use clap::Args;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+use crate::cli::core::download::refresh_library_track;
+use crate::core::data::database::{Database, DatabaseTrack};
+use crate::core::files::lrc_shift::{parse_offset, shift_lrc, OffsetTagMode};
+use crate::core::files::lyrics::LyricsManager;
+use crate::core::files::scanner::lrc_sidecar_path;
+use crate::error::{LrcGetError, Result};
+use crate::services::SimpleServices;
+
+#[derive(Args)]
+pub struct ShiftArgs {
+    /// Audio file, .lrc file or library track id
+    #[arg(value_name = "FILE_OR_TRACK_ID")]
+    target: String,
+
+    /// Shift to apply, e.g. +350ms, -1.5s or 200 (ms); positive values make lines appear later
+    #[arg(long, allow_hyphen_values = true)]
+    offset: String,
+
+    /// Fold an existing [offset:] tag into the timestamps and remove it
+    #[arg(long, conflicts_with = "write_offset_tag")]
+    honor_offset_tag: bool,
+
+    /// Record the shift in the .lrc [offset:] tag instead of rewriting its timestamps
+    #[arg(long)]
+    write_offset_tag: bool,
+
+    /// Show the shifted lyrics without writing anything
+    #[arg(long)]
+    dry_run: bool,
+}
+
+/// Files the shift applies to
+struct ShiftTarget {
+    audio_path: Option<String>,
+    lrc_path: PathBuf,
+    track: Option<DatabaseTrack>,
+}
+
+pub async fn execute(args: ShiftArgs, services: &SimpleServices) -> Result<()> {
+    let offset_ms = parse_offset(&args.offset).map_err(LrcGetError::Validation)?;
+    let mode = if args.honor_offset_tag {
+        OffsetTagMode::Honor
+    } else if args.write_offset_tag {
+        OffsetTagMode::Write
+    } else {
+        OffsetTagMode::Keep
+    };
+
+    let mut db = services.create_database().await?;
+    let target = resolve_target(&db, &args.target).await?;
+    let lyrics_manager = LyricsManager::new();
+    let mut shifted_any = false;
+
+    if target.lrc_path.exists() {
+        let content = std::fs::read_to_string(&target.lrc_path)?;
+        let shifted = shift_lrc(&content, offset_ms, mode);
+        shifted_any = true;
+
+        if args.dry_run {
+            println!("🔍 {} (dry run):\n{}", target.lrc_path.display(), shifted.text);
+        } else {
+            std::fs::write(&target.lrc_path, &shifted.text)?;
+            if mode == OffsetTagMode::Write {
+                println!("✅ Updated the [offset:] tag in {}", target.lrc_path.display());
+            } else {
+                println!("✅ Shifted {} by {:+}ms", target.lrc_path.display(), shifted.applied_ms);
+            }
+        }
+        if shifted.clamped > 0 {
+            println!("⚠️  {} timestamp(s) would have gone below zero and were set to 00:00.00", shifted.clamped);
+        }
+    }
+
+    if let Some(audio_path) = &target.audio_path {
+        let embedded = match lyrics_manager.read_embedded_lyrics(audio_path) {
+            Ok(embedded) => embedded,
+            Err(e) => {
+                warn!("Failed to read embedded lyrics from {}: {}", audio_path, e);
+                Default::default()
+            }
+        };
+
+        if let Some(synced) = embedded.synced {
+            // SYLT frames have no [offset:] tag, so embedded timestamps are always rewritten
+            let embedded_mode = if mode == OffsetTagMode::Write { OffsetTagMode::Keep } else { mode };
+            let shifted = shift_lrc(&synced, offset_ms, embedded_mode);
+            shifted_any = true;
+
+            if args.dry_run {
+                println!("🔍 Embedded lyrics in {} (dry run):\n{}", audio_path, shifted.text);
+            } else {
+                lyrics_manager.embed_lyrics(audio_path, embedded.plain.as_deref(), Some(&shifted.text))?;
+                println!("✅ Shifted embedded lyrics in {} by {:+}ms", audio_path, shifted.applied_ms);
+            }
+        }
+    }
+
+    if !shifted_any {
+        return Err(LrcGetError::Validation(format!("No synced lyrics found for '{}'", args.target)));
+    }
+
+    if args.dry_run {
+        return Ok(());
+    }
+
+    // Keep the library's copy of the lyrics in step with the files
+    match &target.track {
+        Some(track) => refresh_library_track(&mut db, track).await,
+        None => println!("💡 Not in the library; only the files were updated"),
+    }
+
+    Ok(())
+}
+
+/// Work out the audio file, sidecar and library track from a path or track id
+async fn resolve_target(db: &Database, target: &str) -> Result<ShiftTarget> {
+    let path = Path::new(target);
+
+    if !path.exists() {
+        let id: i64 = target.parse().map_err(|_| {
+            LrcGetError::Validation(format!("'{}' is neither an existing file nor a track id", target))
+        })?;
+        let track = db.get_track(id).await.map_err(|_| {
+            LrcGetError::Validation(format!("No track with id {} in the library", id))
+        })?;
+        return Ok(ShiftTarget {
+            audio_path: Some(track.file_path.clone()),
+            lrc_path: lrc_sidecar_path(&track.file_path),
+            track: Some(track),
+        });
+    }
+
+    let is_lrc = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("lrc"));
+    if is_lrc {
+        let canonical = path.canonicalize()?;
+        let track = db.get_all_tracks().await?.into_iter().find(|track| {
+            lrc_sidecar_path(&track.file_path)
+                .canonicalize()
+                .is_ok_and(|sidecar| sidecar == canonical)
+        });
+        // Only the named file is rewritten; the track is looked up to refresh the library
+        return Ok(ShiftTarget {
+            audio_path: None,
+            lrc_path: path.to_path_buf(),
+            track,
+        });
+    }
+
+    let track = match db.get_track_by_path(target).await {
+        Ok(track) => Some(track),
+        Err(_) => {
+            let canonical = path.canonicalize()?.display().to_string();
+            db.get_track_by_path(&canonical).await.ok()
+        }
+    };
+
+    Ok(ShiftTarget {
+        audio_path: Some(target.to_string()),
+        lrc_path: lrc_sidecar_path(target),
+        track,
+    })
+}
@@ -139,7 +139,7 @@ pub async fn execute(args: TemplatesArgs, config: &Config) -> Result<()> {
             }
 
             if missing_only {
-                tracks.retain(|t| t.lrc_lyrics.is_none() && t.txt_lyrics.is_none());
+                tracks.retain(|t| t.is_missing_lyrics());
             }
 
             // Build context and render
@@ -187,6 +187,7 @@ pub async fn execute(args: TemplatesArgs, config: &Config) -> Result<()> {
 
             // Create sample data for testing
             use crate::core::data::database::DatabaseTrack;
+            use crate::core::files::scanner::LyricsSource;
             let sample_tracks = vec![
                 DatabaseTrack {
                     id: 1,
@@ -200,6 +201,9 @@ pub async fn execute(args: TemplatesArgs, config: &Config) -> Result<()> {
                     track_number: Some(1),
                     txt_lyrics: Some("Sample plain lyrics".to_string()),
                     lrc_lyrics: None,
+                    txt_lyrics_source: Some(LyricsSource::Sidecar),
+                    lrc_lyrics_source: None,
+                    instrumental: false,
                 },
                 DatabaseTrack {
                     id: 2,
@@ -213,6 +217,9 @@ pub async fn execute(args: TemplatesArgs, config: &Config) -> Result<()> {
                     track_number: Some(2),
                     txt_lyrics: None,
                     lrc_lyrics: Some("[00:10.00]Sample synced lyrics".to_string()),
+                    txt_lyrics_source: None,
+                    lrc_lyrics_source: Some(LyricsSource::Embedded),
+                    instrumental: false,
                 },
             ];
 
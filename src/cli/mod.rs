@@ -1,9 +1,9 @@
 //! Command Line Interface module
 //!
 //! This module contains all CLI commands organized into logical submodules:
-//! - `core`: Essential operations (init, scan, download, search)
-//! - `operations`: Advanced operations (fetch, watch, batch)
-//! - `management`: Configuration and maintenance (config, cache, export, hooks, templates)
+//! - `core`: Essential operations (init, scan, download, search, browse)
+//! - `operations`: Advanced operations (fetch, watch, batch, publish)
+//! - `management`: Configuration and maintenance (config, cache, export, hooks, templates, lint, shift, flag)
 
 pub mod core;
 pub mod operations;
@@ -1,17 +1,26 @@
 use clap::Args;
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::path::PathBuf;
+use std::io::Write;
+use std::path::{Path, PathBuf};
 use tracing::{info, warn};
 use tokio::time::{sleep, Duration};
 
+use crate::cli::core::download::record_download_result;
 use crate::error::Result;
 use crate::services::ServiceFactory;
-use crate::core::services::lrclib::LyricsDownloader;
+use crate::core::data::database::{Database, DatabaseTrack, DownloadOutcome};
+use crate::core::infrastructure::cache::LyricsCacheInterface;
+use crate::core::files::lrc_lint::{lint_lrc_file, LintSeverity};
+use crate::core::files::playlist::{is_playlist_extension, read_playlist};
+use crate::core::files::scanner::{lrc_sidecar_path, Scanner, Track};
+use crate::core::services::lrclib::{LyricsDownloadResult, LyricsDownloader};
 
 #[derive(Args)]
 pub struct BatchArgs {
-    /// Batch file path (JSON, TOML, or CSV)
+    /// Batch file path (JSON, TOML, CSV, or an M3U/M3U8/PLS playlist)
     #[arg(value_name = "FILE")]
     file: PathBuf,
 
@@ -36,8 +45,12 @@ pub struct BatchArgs {
     continue_on_error: bool,
 
     /// Output results to file
-    #[arg(short, long)]
+    #[arg(long)]
     output: Option<PathBuf>,
+
+    /// Skip items finished by an earlier run, as recorded in the job journal next to the batch file
+    #[arg(long)]
+    resume: bool,
 }
 
 #[derive(Deserialize, Serialize, Debug, Clone)]
@@ -54,19 +67,190 @@ struct BatchItem {
     file_path: Option<String>,
 }
 
-#[derive(Serialize)]
+#[derive(Serialize, Deserialize)]
 struct BatchResult {
     item: BatchItem,
     success: bool,
     message: String,
+    /// Library track the item was matched to
+    track_id: Option<i64>,
+    /// Download outcome; absent when the item could not be resolved
+    outcome: Option<DownloadOutcome>,
+    /// DB, CACHE or API
+    source: Option<String>,
+    synced: bool,
+    plain: bool,
+    lrclib_id: Option<i64>,
     execution_time_ms: u64,
 }
 
+impl BatchResult {
+    fn failed(item: BatchItem, message: String, execution_time_ms: u64) -> Self {
+        Self {
+            item,
+            success: false,
+            message,
+            track_id: None,
+            outcome: None,
+            source: None,
+            synced: false,
+            plain: false,
+            lrclib_id: None,
+            execution_time_ms,
+        }
+    }
+
+    fn from_download(
+        item: BatchItem,
+        track: &DatabaseTrack,
+        download: &anyhow::Result<LyricsDownloadResult>,
+        execution_time_ms: u64,
+    ) -> Self {
+        let track_id = (track.id > 0).then_some(track.id);
+        let result = match download {
+            Ok(result) => result,
+            Err(e) => {
+                return Self {
+                    track_id,
+                    outcome: Some(DownloadOutcome::Error),
+                    ..Self::failed(item, e.to_string(), execution_time_ms)
+                };
+            }
+        };
+
+        let outcome = result.outcome();
+        let source = result.source.map(|source| source.as_str().to_string());
+        let message = match outcome {
+            DownloadOutcome::Synced if result.plain_lyrics => "Saved synced and plain lyrics",
+            DownloadOutcome::Synced => "Saved synced lyrics",
+            DownloadOutcome::Plain => "Saved plain lyrics",
+            DownloadOutcome::Instrumental => "Marked as instrumental",
+            DownloadOutcome::Mismatch => "Rejected lyrics that do not fit the track length",
+            DownloadOutcome::NotFound | DownloadOutcome::Error => "No lyrics found",
+        };
+        let message = match &source {
+            Some(source) if result.found => format!("{} ({})", message, source),
+            _ => message.to_string(),
+        };
+
+        Self {
+            item,
+            success: matches!(outcome, DownloadOutcome::Synced | DownloadOutcome::Plain | DownloadOutcome::Instrumental),
+            message,
+            track_id,
+            outcome: Some(outcome),
+            source,
+            synced: result.synced_lyrics,
+            plain: result.plain_lyrics,
+            lrclib_id: result.lrclib_id,
+            execution_time_ms,
+        }
+    }
+
+    /// Whether a resumed run can skip the item: lyrics were saved or LRCLIB answered that
+    /// it has none. Errors and unresolved items are tried again.
+    fn is_final(&self) -> bool {
+        matches!(
+            self.outcome,
+            Some(DownloadOutcome::Synced
+                | DownloadOutcome::Plain
+                | DownloadOutcome::Instrumental
+                | DownloadOutcome::NotFound
+                | DownloadOutcome::Mismatch)
+        )
+    }
+}
+
+/// One line of the job journal: what happened to the item at `index` in the batch file
+#[derive(Serialize, Deserialize)]
+struct JournalEntry {
+    index: usize,
+    /// Unix timestamp (seconds)
+    completed_at: i64,
+    result: BatchResult,
+}
+
+/// Append-only record of processed items, written as each item finishes so that a crash
+/// or Ctrl+C keeps the progress made so far
+struct BatchJournal {
+    path: PathBuf,
+    file: fs::File,
+}
+
+impl BatchJournal {
+    /// `items.csv` is journaled in `items.csv.journal.jsonl`
+    fn path_for(batch_file: &Path) -> PathBuf {
+        let mut name = batch_file.file_name().unwrap_or_default().to_os_string();
+        name.push(".journal.jsonl");
+        batch_file.with_file_name(name)
+    }
+
+    /// Latest result per item index. Lines cut short by a crash are skipped.
+    fn load(path: &Path) -> Result<BTreeMap<usize, BatchResult>> {
+        let mut results = BTreeMap::new();
+        if !path.exists() {
+            return Ok(results);
+        }
+
+        for line in fs::read_to_string(path)?.lines().filter(|line| !line.trim().is_empty()) {
+            match serde_json::from_str::<JournalEntry>(line) {
+                Ok(entry) => {
+                    results.insert(entry.index, entry.result);
+                }
+                Err(e) => warn!("Skipping unreadable journal line in {}: {}", path.display(), e),
+            }
+        }
+        Ok(results)
+    }
+
+    /// Journal entries only count for the item they were written for; an edited batch
+    /// file may have moved other items to their index
+    fn retain_matching(previous: &mut BTreeMap<usize, BatchResult>, items: &[BatchItem]) {
+        previous.retain(|index, result| {
+            items.get(*index).is_some_and(|item| item.title == result.item.title && item.artist == result.item.artist)
+        });
+    }
+
+    /// Open the journal, keeping earlier entries when resuming and starting over otherwise
+    fn open(path: PathBuf, resume: bool) -> Result<Self> {
+        let file = fs::OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(resume)
+            .truncate(!resume)
+            .open(&path)?;
+
+        // Start on a fresh line if the last run died halfway through writing one
+        let mut journal = Self { path, file };
+        if resume && fs::read(&journal.path)?.last().is_some_and(|byte| *byte != b'\n') {
+            journal.file.write_all(b"\n")?;
+        }
+        Ok(journal)
+    }
+
+    fn append(&mut self, index: usize, result: BatchResult) -> Result<BatchResult> {
+        let entry = JournalEntry {
+            index,
+            completed_at: chrono::Utc::now().timestamp(),
+            result,
+        };
+        let mut line = serde_json::to_string(&entry)?;
+        line.push('\n');
+        self.file.write_all(line.as_bytes())?;
+        self.file.flush()?;
+        Ok(entry.result)
+    }
+}
+
 #[derive(Serialize)]
 struct BatchReport {
     total_items: usize,
     successful: usize,
     failed: usize,
+    /// Results carried over from earlier runs of the same batch file
+    resumed: usize,
+    /// Items not processed yet because the run was interrupted
+    pending: usize,
     execution_time_ms: u64,
     results: Vec<BatchResult>,
 }
@@ -85,6 +269,12 @@ pub async fn execute(args: BatchArgs, config: &crate::config::Config) -> crate::
     let items = load_batch_file(&args.file).await?;
     info!("📥 Loaded {} items from batch file", items.len());
 
+    if args.resume && args.operation != "download" {
+        return Err(crate::error::LrcGetError::Validation(
+            "--resume only applies to the download operation".to_string()
+        ));
+    }
+
     if args.dry_run {
         info!("🧪 DRY RUN - would process {} items:", items.len());
         for (i, item) in items.iter().enumerate() {
@@ -109,8 +299,12 @@ pub async fn execute(args: BatchArgs, config: &crate::config::Config) -> crate::
 }
 
 async fn load_batch_file(file_path: &PathBuf) -> Result<Vec<BatchItem>> {
-    let content = fs::read_to_string(file_path)?;
     let extension = file_path.extension().and_then(|s| s.to_str()).unwrap_or("");
+    if is_playlist_extension(extension) {
+        return load_playlist(file_path).await;
+    }
+
+    let content = fs::read_to_string(file_path)?;
 
     match extension.to_lowercase().as_str() {
         "json" => {
@@ -128,11 +322,48 @@ async fn load_batch_file(file_path: &PathBuf) -> Result<Vec<BatchItem>> {
             parse_csv(&content)
         },
         _ => Err(crate::error::LrcGetError::Validation(
-            "Unsupported batch file format. Use .json, .toml, or .csv".to_string()
+            "Unsupported batch file format. Use .json, .toml, .csv, .m3u, .m3u8, or .pls".to_string()
         )),
     }
 }
 
+/// Turn playlist entries into items, reading each file's tags and falling back to the
+/// playlist's own `#EXTINF`/`TitleN=` metadata for files without usable tags
+async fn load_playlist(file_path: &Path) -> Result<Vec<BatchItem>> {
+    let scanner = Scanner::new();
+    let mut items = Vec::new();
+
+    for entry in read_playlist(file_path)? {
+        let entry_path = entry.path.display().to_string();
+
+        if let Some(track) = scanner.scan_file(&entry.path).await? {
+            items.push(BatchItem {
+                id: None,
+                title: track.title,
+                artist: track.artist,
+                album: Some(track.album).filter(|album| !album.is_empty()),
+                duration: Some(track.duration).filter(|duration| *duration > 0.0),
+                file_path: Some(entry_path),
+            });
+            continue;
+        }
+
+        match (entry.title, entry.artist) {
+            (Some(title), Some(artist)) => items.push(BatchItem {
+                id: None,
+                title,
+                artist,
+                album: None,
+                duration: entry.duration,
+                file_path: Some(entry_path),
+            }),
+            _ => warn!("Skipping playlist entry without tags or artist/title info: {}", entry_path),
+        }
+    }
+
+    Ok(items)
+}
+
 fn parse_csv(content: &str) -> Result<Vec<BatchItem>> {
     let mut items = Vec::new();
     let mut lines = content.lines();
@@ -182,85 +413,119 @@ fn parse_csv_line(line: &str) -> Result<Option<BatchItem>> {
 async fn execute_batch_download(args: BatchArgs, factory: &ServiceFactory, items: Vec<BatchItem>) -> Result<()> {
     info!("⬇️ Starting batch download for {} items", items.len());
 
-    // Use ServiceFactory to eliminate duplication
-    let _bundle = factory.create_full_bundle().await?;
-    let downloader = factory.create_lyrics_downloader();
+    let mut db = factory.create_database().await?;
+    let cache = factory.create_lyrics_cache()?;
+    let downloader = factory.create_lyrics_downloader(cache.clone());
     let start_time = std::time::Instant::now();
-    
-    let semaphore = std::sync::Arc::new(tokio::sync::Semaphore::new(args.parallel));
-    let mut results: Vec<BatchResult> = Vec::new();
-    let mut tasks = Vec::new();
-    
-    for item in items {
-        let downloader = downloader.clone();
-        let semaphore = semaphore.clone();
-        let delay = args.delay;
-        let continue_on_error = args.continue_on_error;
-        
-        let task = tokio::spawn(async move {
+    let total_items = items.len();
+
+    let journal_path = BatchJournal::path_for(&args.file);
+    let mut previous = if args.resume {
+        BatchJournal::load(&journal_path)?
+    } else {
+        BTreeMap::new()
+    };
+    BatchJournal::retain_matching(&mut previous, &items);
+    let mut journal = BatchJournal::open(journal_path, args.resume)?;
+
+    let mut resolved = Vec::with_capacity(items.len());
+    for (index, item) in items.into_iter().enumerate() {
+        if previous.get(&index).is_some_and(BatchResult::is_final) {
+            continue;
+        }
+        let track = resolve_item(&db, &item).await;
+        resolved.push((index, item, track));
+    }
+
+    let resumed = previous.values().filter(|result| result.is_final()).count();
+    if args.resume {
+        info!("⏭️ Resuming: {} items already done, {} to go ({})", resumed, resolved.len(), journal.path.display());
+    }
+
+    // Run on this task like `lrcget download`: the downloader may hold the local LRCLIB
+    // database, which cannot move between threads
+    let downloader = &downloader;
+    let delay = args.delay;
+    let continue_on_error = args.continue_on_error;
+    let mut downloads = std::pin::pin!(stream::iter(resolved)
+        .map(|(index, item, track)| async move {
             let item_start = std::time::Instant::now();
 
-            // Acquire a permit; if semaphore is closed, mark as failed gracefully
-            let permit = match semaphore.acquire().await {
-                Ok(p) => p,
-                Err(_) => {
-                    return BatchResult {
-                        item,
-                        success: false,
-                        message: "Semaphore closed while acquiring permit".to_string(),
-                        execution_time_ms: item_start.elapsed().as_millis() as u64,
-                    };
+            let track = match track {
+                Ok(track) => track,
+                Err(message) => {
+                    warn!("❌ Skipping {} - {}: {}", item.artist, item.title, message);
+                    return (index, BatchResult::failed(item, message, 0), None);
                 }
             };
-            let _permit = permit;
-            
+
             // Add delay between requests
             if delay > 0 {
                 sleep(Duration::from_millis(delay)).await;
             }
-            
-            let result = process_download_item(&downloader, &item).await;
-            
+
+            let download = process_download_item(downloader, &track).await;
+
             let execution_time = item_start.elapsed().as_millis() as u64;
-            
-            match result {
-                Ok(message) => BatchResult {
-                    item,
-                    success: true,
-                    message,
-                    execution_time_ms: execution_time,
-                },
-                Err(e) => {
-                    let error_msg = e.to_string();
-                    if !continue_on_error {
-                        warn!("❌ Failed to download lyrics for {}: {}", item.title, error_msg);
-                    }
-                    BatchResult {
-                        item,
-                        success: false,
-                        message: error_msg,
-                        execution_time_ms: execution_time,
-                    }
+
+            if let Err(e) = &download {
+                if !continue_on_error {
+                    warn!("❌ Failed to download lyrics for {}: {}", item.title, e);
                 }
             }
-        });
-        
-        tasks.push(task);
+
+            (index, BatchResult::from_download(item, &track, &download, execution_time), Some((track, download)))
+        })
+        .buffered(args.parallel.max(1)));
+
+    let mut ctrl_c = std::pin::pin!(tokio::signal::ctrl_c());
+    let mut interrupted = false;
+
+    loop {
+        let (index, result, download) = tokio::select! {
+            next = downloads.next() => match next {
+                Some(next) => next,
+                None => break,
+            },
+            _ = &mut ctrl_c => {
+                interrupted = true;
+                break;
+            }
+        };
+
+        // Record what happened to library tracks
+        if let Some((track, download)) = download.filter(|(track, _)| track.id > 0) {
+            record_download_result(&mut db, &track, &download).await;
+        }
+
+        let result = journal.append(index, result)?;
+        previous.insert(index, result);
     }
-    
-    // Wait for all tasks to complete
-    for task in tasks {
-        results.push(task.await?);
+
+    if let Err(e) = cache.read().await.save_index().await {
+        warn!("Failed to save cache index: {}", e);
     }
+
+    // Merge this run's results with the ones carried over, in batch file order
+    let results: Vec<BatchResult> = previous.into_values().collect();
     
     let total_time = start_time.elapsed().as_millis() as u64;
     let successful = results.iter().filter(|r| r.success).count();
     let failed = results.len() - successful;
+    let count = |outcome: DownloadOutcome| results.iter().filter(|r| r.outcome == Some(outcome)).count();
+    let (synced, plain, instrumental, not_found) = (
+        count(DownloadOutcome::Synced),
+        count(DownloadOutcome::Plain),
+        count(DownloadOutcome::Instrumental),
+        count(DownloadOutcome::NotFound) + count(DownloadOutcome::Mismatch),
+    );
     
     let report = BatchReport {
-        total_items: results.len(),
+        total_items,
         successful,
         failed,
+        resumed,
+        pending: total_items - results.len(),
         execution_time_ms: total_time,
         results,
     };
@@ -275,25 +540,84 @@ async fn execute_batch_download(args: BatchArgs, factory: &ServiceFactory, items
     // Summary
     println!("\n📊 Batch Download Summary:");
     println!("  ✅ Successful: {}", successful);
+    println!("    🎵 Synced: {}", synced);
+    println!("    📝 Plain: {}", plain);
+    println!("    🎹 Instrumental: {}", instrumental);
     println!("  ❌ Failed: {}", failed);
+    println!("    🔍 Not found: {}", not_found);
+    if report.resumed > 0 {
+        println!("  ⏭️ From earlier runs: {}", report.resumed);
+    }
     println!("  ⏱️ Total Time: {:.2}s", total_time as f64 / 1000.0);
     println!("  📈 Success Rate: {:.1}%", (successful as f64 / report.total_items as f64) * 100.0);
+
+    if interrupted || report.pending > 0 {
+        println!("\n⏸️ Interrupted with {} items left. Run again with --resume to continue", report.pending);
+    }
     
     Ok(())
 }
 
-async fn process_download_item(_downloader: &LyricsDownloader, item: &BatchItem) -> Result<String> {
-    // This is a simplified version - in reality we'd need to create a DatabaseTrack
-    // from the BatchItem or integrate more deeply with the database
-    info!("Processing: {} - {}", item.artist, item.title);
-    
-    // For now, return a mock success - in full implementation this would:
-    // 1. Search for the track in LRCLIB
-    // 2. Download lyrics if found
-    // 3. Save to appropriate files
-    // 4. Update database if track exists
-    
-    Ok(format!("Downloaded lyrics for {} - {}", item.artist, item.title))
+/// Find the track an item refers to. Items with a file path are downloaded next to that
+/// file (using its library entry if there is one); other items must be in the library.
+async fn resolve_item(db: &Database, item: &BatchItem) -> std::result::Result<DatabaseTrack, String> {
+    let Some(file_path) = &item.file_path else {
+        return match db.find_track(&item.title, &item.artist, item.album.as_deref()).await {
+            Ok(Some(track)) => Ok(track),
+            Ok(None) => Err("Not found in the library; add a file_path or run 'lrcget scan'".to_string()),
+            Err(e) => Err(format!("Library lookup failed: {}", e)),
+        };
+    };
+
+    let path = Path::new(file_path);
+    if !path.exists() {
+        return Err(format!("File does not exist: {}", file_path));
+    }
+
+    // Playlists and hand-written files often use relative paths
+    let mut candidates = vec![file_path.clone()];
+    if let Ok(canonical) = path.canonicalize() {
+        candidates.push(canonical.display().to_string());
+    }
+    for candidate in &candidates {
+        match db.find_track_by_path(candidate).await {
+            Ok(Some(track)) => return Ok(track),
+            Ok(None) => {}
+            // Downloading it as an unknown file would leave the library untouched
+            Err(e) => return Err(format!("Library lookup failed: {}", e)),
+        }
+    }
+
+    // Not in the library: the item describes the track, the file's tags fill the gaps
+    let tags = Track::new_from_path(path).ok();
+    Ok(DatabaseTrack {
+        id: 0,
+        file_path: file_path.clone(),
+        file_name: path.file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown")
+            .to_string(),
+        title: item.title.clone(),
+        artist_name: item.artist.clone(),
+        album_name: item.album.clone()
+            .or_else(|| tags.as_ref().map(|t| t.album.clone()))
+            .unwrap_or_default(),
+        album_artist: tags.as_ref().map(|t| t.album_artist.clone()).unwrap_or_default(),
+        duration: item.duration
+            .or_else(|| tags.as_ref().map(|t| t.duration))
+            .unwrap_or(0.0),
+        track_number: tags.as_ref().and_then(|t| t.track_number).map(|n| n as i64),
+        txt_lyrics: None,
+        lrc_lyrics: None,
+        txt_lyrics_source: None,
+        lrc_lyrics_source: None,
+        instrumental: false,
+    })
+}
+
+async fn process_download_item(downloader: &LyricsDownloader, track: &DatabaseTrack) -> anyhow::Result<LyricsDownloadResult> {
+    info!("Processing: {} - {}", track.artist_name, track.title);
+    downloader.download_for_track(track).await
 }
 
 async fn execute_batch_search(args: BatchArgs, factory: &ServiceFactory, items: Vec<BatchItem>) -> Result<()> {
@@ -330,22 +654,48 @@ async fn execute_batch_validate(_args: BatchArgs, _factory: &ServiceFactory, ite
     
     for item in &items {
         let mut is_valid = true;
-        let mut errors = Vec::new();
+        let mut errors: Vec<String> = Vec::new();
+        let mut warnings: Vec<String> = Vec::new();
         
         if item.title.is_empty() {
-            errors.push("Missing title");
+            errors.push("Missing title".to_string());
             is_valid = false;
         }
         
         if item.artist.is_empty() {
-            errors.push("Missing artist");
+            errors.push("Missing artist".to_string());
             is_valid = false;
         }
         
         if let Some(file_path) = &item.file_path {
             if !std::path::Path::new(file_path).exists() {
-                errors.push("File does not exist");
+                errors.push("File does not exist".to_string());
                 is_valid = false;
+            } else {
+                // Check the .lrc sidecar with the same engine as `lrcget lint`
+                let lrc_path = lrc_sidecar_path(file_path);
+                if lrc_path.exists() {
+                    match lint_lrc_file(&lrc_path, item.duration) {
+                        Ok(lint) => {
+                            for issue in lint.issues {
+                                let message = match issue.line {
+                                    Some(line) => format!(".lrc line {}: {}", line, issue.message),
+                                    None => format!(".lrc: {}", issue.message),
+                                };
+                                if issue.kind.severity() == LintSeverity::Error {
+                                    errors.push(message);
+                                    is_valid = false;
+                                } else {
+                                    warnings.push(message);
+                                }
+                            }
+                        }
+                        Err(e) => {
+                            errors.push(format!("Cannot read .lrc: {}", e));
+                            is_valid = false;
+                        }
+                    }
+                }
             }
         }
         
@@ -356,6 +706,11 @@ async fn execute_batch_validate(_args: BatchArgs, _factory: &ServiceFactory, ite
             println!("❌ Invalid: {} - {} ({})", 
                 item.artist, item.title, errors.join(", "));
         }
+
+        if !warnings.is_empty() {
+            println!("⚠️ Warning: {} - {} ({})",
+                item.artist, item.title, warnings.join(", "));
+        }
     }
     
     println!("\n📊 Validation Summary:");
@@ -364,4 +719,115 @@ async fn execute_batch_validate(_args: BatchArgs, _factory: &ServiceFactory, ite
     println!("  📈 Valid Rate: {:.1}%", (valid_count as f64 / items.len() as f64) * 100.0);
     
     Ok(())
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    fn item(title: &str, file_path: Option<&str>) -> BatchItem {
+        BatchItem {
+            id: None,
+            title: title.to_string(),
+            artist: "Artist".to_string(),
+            album: None,
+            duration: None,
+            file_path: file_path.map(str::to_string),
+        }
+    }
+
+    fn result(title: &str, outcome: DownloadOutcome) -> BatchResult {
+        BatchResult {
+            outcome: Some(outcome),
+            ..BatchResult::failed(item(title, None), String::new(), 0)
+        }
+    }
+
+    #[test]
+    fn test_journal_resumes_after_a_torn_last_line() {
+        let dir = TestDir::new("batch");
+        let path = BatchJournal::path_for(&dir.path().join("tracks.csv"));
+        let mut journal = BatchJournal::open(path.clone(), false).unwrap();
+        journal.append(0, result("A", DownloadOutcome::Synced)).unwrap();
+        journal.append(1, result("B", DownloadOutcome::Error)).unwrap();
+        drop(journal);
+
+        // A crash halfway through writing the third entry
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(br#"{"index":2,"completed_at":1,"res"#).unwrap();
+        drop(file);
+
+        let previous = BatchJournal::load(&path).unwrap();
+        assert_eq!(previous.keys().copied().collect::<Vec<_>>(), vec![0, 1]);
+        assert!(previous[&0].is_final());
+        assert!(!previous[&1].is_final());
+
+        // The next entry starts on its own line instead of joining the torn one
+        let mut journal = BatchJournal::open(path.clone(), true).unwrap();
+        journal.append(1, result("B", DownloadOutcome::NotFound)).unwrap();
+        journal.append(2, result("C", DownloadOutcome::Plain)).unwrap();
+        let previous = BatchJournal::load(&path).unwrap();
+        assert_eq!(previous.len(), 3);
+        assert!(previous.values().all(BatchResult::is_final));
+
+        // Without --resume the journal starts over
+        BatchJournal::open(path.clone(), false).unwrap();
+        assert!(BatchJournal::load(&path).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_journal_entries_of_changed_batch_file_are_dropped() {
+        let mut previous = BTreeMap::from([
+            (0, result("A", DownloadOutcome::Synced)),
+            (1, result("B", DownloadOutcome::Synced)),
+            (2, result("C", DownloadOutcome::Synced)),
+        ]);
+
+        // "B" was removed, moving "C" up; the file now ends before index 2
+        let items = vec![item("A", None), item("C", None)];
+        BatchJournal::retain_matching(&mut previous, &items);
+        assert_eq!(previous.keys().copied().collect::<Vec<_>>(), vec![0]);
+    }
+
+    #[tokio::test]
+    async fn test_resolve_item_prefers_library_tracks() {
+        let test_dir = TestDir::new("batch");
+        let dir = test_dir.path();
+        let known = dir.join("known.mp3").display().to_string();
+        let unknown = dir.join("unknown.mp3").display().to_string();
+        std::fs::write(&known, b"audio").unwrap();
+        std::fs::write(&unknown, b"audio").unwrap();
+
+        let mut db = Database::new(Path::new(":memory:")).await.unwrap();
+        db.add_track(&Track {
+            file_path: known.clone(),
+            file_name: "known.mp3".to_string(),
+            title: "Known".to_string(),
+            album: "Album".to_string(),
+            artist: "Artist".to_string(),
+            album_artist: "Artist".to_string(),
+            duration: 180.0,
+            txt_lyrics: None,
+            lrc_lyrics: None,
+            track_number: None,
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            fingerprint: None,
+            content_hash: None,
+            instrumental: false,
+        }).await.unwrap();
+
+        let track = resolve_item(&db, &item("Known", Some(&known))).await.unwrap();
+        assert!(track.id > 0);
+        assert_eq!(track.album_name, "Album");
+
+        // Files outside the library are downloaded without a track id
+        let track = resolve_item(&db, &item("Unknown", Some(&unknown))).await.unwrap();
+        assert_eq!((track.id, track.title.as_str()), (0, "Unknown"));
+
+        assert!(resolve_item(&db, &item("Gone", Some("/nonexistent/gone.mp3"))).await.is_err());
+        assert_eq!(resolve_item(&db, &item("Known", None)).await.unwrap().file_path, known);
+        assert!(resolve_item(&db, &item("Missing", None)).await.is_err());
+    }
+}
@@ -5,7 +5,9 @@ use tracing::{info, warn};
 
 use crate::config::Config;
 use crate::core::files::scanner::Track;
+use crate::core::files::duration_check::check_duration;
 use crate::core::files::lyrics::LyricsManager;
+use crate::core::services::lrclib::SearchResult;
 
 #[derive(Args)]
 pub struct FetchArgs {
@@ -28,6 +30,14 @@ pub struct FetchArgs {
     /// Enable fuzzy search as fallback when exact match fails
     #[arg(long)]
     fuzzy_search: bool,
+
+    /// Apply this LRCLIB record instead of matching by metadata; replaces existing lyrics
+    #[arg(long, value_name = "ID", conflicts_with_all = ["fuzzy_search", "cached_only"])]
+    lrclib_id: Option<i64>,
+
+    /// Only use lyrics LRCLIB already has, never triggering a scrape of external sources
+    #[arg(long)]
+    cached_only: bool,
 }
 
 pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
@@ -57,7 +67,7 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
 
     // Extract metadata from the audio file
     info!("🎵 Extracting metadata...");
-    let track = match Track::new_from_path(file_path) {
+    let mut track = match Track::new_from_path(file_path) {
         Ok(track) => track,
         Err(e) => {
             anyhow::bail!("Failed to extract metadata from file: {}", e);
@@ -69,8 +79,8 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
     info!("💿 Album: {}", track.album);
     info!("⏱️  Duration: {:.1}s", track.duration);
 
-    // Check if lyrics already exist
-    if !args.force {
+    // Check if lyrics already exist; picking a record by id is an explicit replacement
+    if !args.force && args.lrclib_id.is_none() {
         if track.lrc_lyrics.is_some() {
             println!("⚠️  Synced lyrics already exist. Use --force to overwrite.");
             return Ok(());
@@ -79,6 +89,10 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
             println!("⚠️  Plain lyrics already exist. Use --force to overwrite.");
             return Ok(());
         }
+        if track.instrumental {
+            println!("⚠️  Track is marked as instrumental. Use --force to overwrite.");
+            return Ok(());
+        }
     }
 
     // Initialize database for saving track info
@@ -90,12 +104,27 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
     
     // Try exact match first with rounded duration
     let rounded_duration = track.duration.round();
-    let lyrics = client.get_lyrics(
-        &track.title,
-        &track.artist,
-        &track.album,
-        rounded_duration,
-    ).await?;
+    let lyrics = if let Some(lrclib_id) = args.lrclib_id {
+        let Some(record) = client.get_lyrics_by_id(lrclib_id).await? else {
+            anyhow::bail!("No LRCLIB record with id {}", lrclib_id);
+        };
+        describe_record(&record, &track, config);
+        Some(record)
+    } else if args.cached_only {
+        client.get_lyrics_cached(
+            &track.title,
+            &track.artist,
+            &track.album,
+            rounded_duration,
+        ).await?
+    } else {
+        client.get_lyrics(
+            &track.title,
+            &track.artist,
+            &track.album,
+            rounded_duration,
+        ).await?
+    };
 
     if let Some(lyrics_data) = lyrics {
         if args.dry_run {
@@ -125,6 +154,8 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
             println!("✅ Saved instrumental marker");
 
             // Save track to database
+            track.instrumental = true;
+            track.lrc_lyrics = None;
             match db.add_track(&track).await {
                 Ok(()) => {
                     info!("✅ Saved instrumental track to database");
@@ -138,17 +169,33 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
 
         // Save lyrics
         let lyrics_manager = LyricsManager::new();
-        lyrics_manager.save_lyrics_for_file(
-            &track.file_path,
-            lyrics_data.plain_lyrics.as_deref(),
-            lyrics_data.synced_lyrics.as_deref(),
-            false,
-        ).await?;
-
-        if let Some(_) = &lyrics_data.synced_lyrics {
-            println!("✅ Saved synced lyrics (.lrc)");
-        } else if let Some(_) = &lyrics_data.plain_lyrics {
-            println!("✅ Saved plain lyrics (.txt)");
+        let storage = config.lyrics_storage_policy().for_path(&track.file_path);
+
+        if storage.writes_sidecar() {
+            lyrics_manager.save_lyrics_for_file(
+                &track.file_path,
+                lyrics_data.plain_lyrics.as_deref(),
+                lyrics_data.synced_lyrics.as_deref(),
+                false,
+            ).await?;
+
+            if let Some(_) = &lyrics_data.synced_lyrics {
+                println!("✅ Saved synced lyrics (.lrc)");
+            } else if let Some(_) = &lyrics_data.plain_lyrics {
+                println!("✅ Saved plain lyrics (.txt)");
+            }
+        }
+
+        if storage.embeds() {
+            match lyrics_manager.embed_lyrics(
+                &track.file_path,
+                lyrics_data.plain_lyrics.as_deref(),
+                lyrics_data.synced_lyrics.as_deref(),
+            ) {
+                Ok(()) => println!("✅ Embedded lyrics into audio file"),
+                Err(e) if storage.writes_sidecar() => warn!("⚠️ Failed to embed lyrics: {}", e),
+                Err(e) => return Err(e),
+            }
         }
 
         // Save track to database
@@ -215,17 +262,33 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
             }
 
             let lyrics_manager = LyricsManager::new();
-            lyrics_manager.save_lyrics_for_file(
-                &track.file_path,
-                search_result.plain_lyrics.as_deref(),
-                search_result.synced_lyrics.as_deref(),
-                search_result.instrumental,
-            ).await?;
+            let storage = config.lyrics_storage_policy().for_path(&track.file_path);
+
+            if search_result.instrumental || storage.writes_sidecar() {
+                lyrics_manager.save_lyrics_for_file(
+                    &track.file_path,
+                    search_result.plain_lyrics.as_deref(),
+                    search_result.synced_lyrics.as_deref(),
+                    search_result.instrumental,
+                ).await?;
+
+                if search_result.synced_lyrics.is_some() {
+                    println!("✅ Saved synced lyrics (.lrc) from search result");
+                } else if search_result.plain_lyrics.is_some() {
+                    println!("✅ Saved plain lyrics (.txt) from search result");
+                }
+            }
 
-            if search_result.synced_lyrics.is_some() {
-                println!("✅ Saved synced lyrics (.lrc) from search result");
-            } else if search_result.plain_lyrics.is_some() {
-                println!("✅ Saved plain lyrics (.txt) from search result");
+            if !search_result.instrumental && storage.embeds() {
+                match lyrics_manager.embed_lyrics(
+                    &track.file_path,
+                    search_result.plain_lyrics.as_deref(),
+                    search_result.synced_lyrics.as_deref(),
+                ) {
+                    Ok(()) => println!("✅ Embedded lyrics into audio file"),
+                    Err(e) if storage.writes_sidecar() => warn!("⚠️ Failed to embed lyrics: {}", e),
+                    Err(e) => return Err(e),
+                }
             }
 
             // Save track to database
@@ -245,7 +308,24 @@ pub async fn execute(args: FetchArgs, config: &Config) -> Result<()> {
     Ok(())
 }
 
-fn find_best_match<'a>(track: &Track, results: &'a [crate::core::services::lrclib::SearchResult]) -> Option<&'a crate::core::services::lrclib::SearchResult> {
+/// Show which record `--lrclib-id` picked, warning when its length does not fit the file
+fn describe_record(record: &SearchResult, track: &Track, config: &Config) {
+    println!("🎵 LRCLIB #{}: {} - {} ({})",
+        record.id.map(|id| id.to_string()).unwrap_or_default(),
+        record.artist_name.as_deref().unwrap_or("Unknown"),
+        record.name.as_deref().unwrap_or("Unknown"),
+        record.album_name.as_deref().unwrap_or("Unknown"),
+    );
+
+    let threshold = config.duration_policy().threshold_seconds;
+    if let Some(check) = check_duration(track.duration, record.duration, record.synced_lyrics.as_deref(), threshold) {
+        if check.mismatch {
+            println!("⚠️  This record may be another version of the song: {}", check.describe());
+        }
+    }
+}
+
+fn find_best_match<'a>(track: &Track, results: &'a [SearchResult]) -> Option<&'a SearchResult> {
     let mut best_score = 0.0;
     let mut best_match = None;
     
@@ -1,10 +1,11 @@
 //! Advanced operations for power users
 //!
 //! This module contains advanced commands for complex workflows:
-//! fetching specific lyrics, monitoring file changes, and batch processing.
+//! fetching specific lyrics, monitoring file changes, batch processing, and publishing lyrics.
 
 pub mod fetch;
 pub mod watch;
 pub mod batch;
+pub mod publish;
 
 // Re-export for convenience
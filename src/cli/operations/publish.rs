@@ -0,0 +1,123 @@
+use clap::Args;
+use std::path::Path;
+use tracing::info;
+
+use crate::core::files::lrc_lint::{lint_lrc, LintSeverity};
+use crate::core::files::lyrics::strip_timestamps;
+use crate::core::files::scanner::Track;
+use crate::core::services::challenge::solve_challenge;
+use crate::core::services::lrclib::PublishRequest;
+use crate::error::{LrcGetError, Result};
+use crate::services::SimpleServices;
+
+#[derive(Args)]
+pub struct PublishArgs {
+    /// Audio file or library track id whose local lyrics to publish
+    #[arg(value_name = "FILE_OR_TRACK_ID")]
+    target: String,
+
+    /// Publish only plain lyrics even if a .lrc file exists
+    #[arg(long)]
+    plain_only: bool,
+
+    /// Show what would be published without contacting LRCLIB
+    #[arg(long)]
+    dry_run: bool,
+}
+
+pub async fn execute(args: PublishArgs, services: &SimpleServices) -> Result<()> {
+    let path = resolve_path(services, &args.target).await?;
+    // Read the files again rather than trusting the library, which may predate hand edits
+    let track = Track::new_from_path(Path::new(&path))?;
+
+    if track.duration <= 0.0 {
+        return Err(LrcGetError::Validation(format!("Could not read the length of {}", path)));
+    }
+
+    let request = build_request(&track, args.plain_only)?;
+    let kind = if track.instrumental {
+        "instrumental marker"
+    } else if !request.synced_lyrics.is_empty() {
+        "synced lyrics"
+    } else {
+        "plain lyrics"
+    };
+
+    println!("🎵 {} - {} ({}, {:.0}s)", request.artist_name, request.track_name, request.album_name, request.duration);
+    println!("📝 Publishing {}", kind);
+
+    if args.dry_run {
+        println!("🔍 DRY RUN - nothing was sent to LRCLIB");
+        if !request.synced_lyrics.is_empty() {
+            println!("{}", request.synced_lyrics);
+        } else if !request.plain_lyrics.is_empty() {
+            println!("{}", request.plain_lyrics);
+        }
+        return Ok(());
+    }
+
+    let client = services.create_lrclib_client();
+
+    info!("Requesting a publish challenge");
+    let challenge = client.request_challenge().await?;
+
+    println!("⛏️  Solving the publish challenge, this can take a little while...");
+    let (prefix, target) = (challenge.prefix.clone(), challenge.target.clone());
+    let nonce = tokio::task::spawn_blocking(move || solve_challenge(&prefix, &target))
+        .await
+        .map_err(|e| LrcGetError::Internal(e.into()))??;
+
+    client.publish(&request, &format!("{}:{}", challenge.prefix, nonce)).await?;
+    println!("✅ Published {} - {} to LRCLIB", request.artist_name, request.track_name);
+
+    Ok(())
+}
+
+/// A track id is looked up in the library; anything else must be an existing file
+async fn resolve_path(services: &SimpleServices, target: &str) -> Result<String> {
+    if Path::new(target).exists() {
+        return Ok(target.to_string());
+    }
+
+    let id: i64 = target.parse().map_err(|_| {
+        LrcGetError::Validation(format!("'{}' is neither an existing file nor a track id", target))
+    })?;
+    let db = services.create_database().await?;
+    let track = db.get_track(id).await.map_err(|_| {
+        LrcGetError::Validation(format!("No track with id {} in the library", id))
+    })?;
+    Ok(track.file_path)
+}
+
+fn build_request(track: &Track, plain_only: bool) -> Result<PublishRequest> {
+    let synced = if plain_only { None } else { track.lrc_lyrics.clone() };
+
+    if let Some(synced) = &synced {
+        let lint = lint_lrc(synced.as_bytes(), Some(track.duration));
+        if let Some(issue) = lint.issues.iter().find(|issue| issue.kind.severity() == LintSeverity::Error) {
+            return Err(LrcGetError::Validation(format!(
+                "The synced lyrics have problems ({}); run `lrcget lint --fix` or pass --plain-only",
+                issue.message
+            )));
+        }
+    }
+
+    // LRCLIB wants plain lyrics alongside synced ones; derive them when there is no .txt
+    let plain = track
+        .txt_lyrics
+        .clone()
+        .or_else(|| synced.as_deref().map(strip_timestamps));
+
+    if !track.instrumental && plain.is_none() && synced.is_none() {
+        return Err(LrcGetError::Validation(format!("No local lyrics found for {}", track.file_path)));
+    }
+
+    Ok(PublishRequest {
+        track_name: track.title.clone(),
+        artist_name: track.artist.clone(),
+        album_name: track.album.clone(),
+        duration: track.duration.round(),
+        plain_lyrics: plain.unwrap_or_default(),
+        synced_lyrics: synced.unwrap_or_default(),
+    })
+}
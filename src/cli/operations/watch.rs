@@ -3,7 +3,9 @@ use anyhow::Result;
 use notify::{Event, EventKind, RecursiveMode, Watcher};
 use std::path::PathBuf;
 use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
 use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
 use tokio::time;
 use tracing::{warn};
 use futures::{channel::mpsc::Receiver, StreamExt};
@@ -11,7 +13,9 @@ use std::io::Write;
 use chrono::Utc;
 
 use crate::config::Config;
+use crate::cli::core::download::record_download_result;
 use crate::core::data::database::Database;
+use crate::core::infrastructure::cache::LyricsCacheInterface;
 use crate::core::files::scanner::Scanner;
 use crate::core::services::lrclib::LyricsDownloader;
 
@@ -263,9 +267,10 @@ pub async fn execute(mut args: WatchArgs, config: &Config) -> Result<()> {
     // Initialize session state
     let mut session = WatchSession::new(watch_config.clone());
 
-    // Setup database and downloader
+    // Setup database and downloader; the cache index is saved when the watcher stops
     let mut db = Database::new(&config.database_path).await?;
-    let downloader = LyricsDownloader::from_client(config.create_lrclib_client());
+    let cache = Arc::new(RwLock::new(config.create_lyrics_cache()?));
+    let downloader = config.create_lyrics_downloader(Some(cache.clone()));
 
     // Log startup
     log_docker_startup(&watch_config);
@@ -325,7 +330,13 @@ pub async fn execute(mut args: WatchArgs, config: &Config) -> Result<()> {
     log_with_timestamp("INFO", "File system watcher started");
 
     // Main event loop
-    process_watch_events(rx, args, config, &mut db, &downloader, &mut session).await
+    let result = process_watch_events(rx, args, config, &mut db, &downloader, &mut session).await;
+
+    if let Err(e) = cache.read().await.save_index().await {
+        warn!("Failed to save cache index: {}", e);
+    }
+
+    result
 }
 
 async fn process_watch_events(
@@ -339,9 +350,15 @@ async fn process_watch_events(
     let mut pending_files = HashSet::new();
     let mut debounce_timer = time::interval(Duration::from_secs(args.debounce_seconds));
     let scanner = Scanner::new();
+    let mut shutdown = std::pin::pin!(shutdown_signal());
 
     loop {
         tokio::select! {
+            _ = &mut shutdown => {
+                log_with_timestamp("INFO", "Shutdown requested, stopping watcher");
+                break;
+            }
+
             // Process file system events
             event_result = rx.next() => {
                 match event_result {
@@ -396,6 +413,22 @@ async fn process_watch_events(
     Ok(())
 }
 
+/// Resolves on Ctrl+C or SIGTERM, so a container stop also ends the watcher cleanly
+async fn shutdown_signal() {
+    match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+        Ok(mut sigterm) => {
+            tokio::select! {
+                _ = tokio::signal::ctrl_c() => {}
+                _ = sigterm.recv() => {}
+            }
+        }
+        Err(e) => {
+            warn!("Failed to listen for SIGTERM: {}", e);
+            let _ = tokio::signal::ctrl_c().await;
+        }
+    }
+}
+
 async fn handle_fs_event(
     event: Event,
     args: &WatchArgs,
@@ -452,11 +485,19 @@ async fn process_file(
                 return Ok(());
             }
 
+            if track_metadata.instrumental {
+                log_with_timestamp("INFO", &format!("SKIP {} - Marked as instrumental", truncate_path_for_log(file_path)));
+                return Ok(());
+            }
+
             // First, save the track to the database
             let saved_track_id = match db.add_track(&track_metadata).await {
                 Ok(()) => {
                     log_with_timestamp("INFO", &format!("SAVED {} to database", truncate_path_for_log(file_path)));
-                    1 // We saved it successfully, use placeholder ID
+                    db.get_track_by_path(&track_metadata.file_path)
+                        .await
+                        .map(|track| track.id)
+                        .unwrap_or(0)
                 }
                 Err(e) => {
                     log_with_timestamp("WARN", &format!("DB_ERROR {} - Failed to save to database: {}", truncate_path_for_log(file_path), e));
@@ -483,20 +524,26 @@ async fn process_file(
                 track_number: track_metadata.track_number.map(|n| n as i64),
                 lrc_lyrics: None,
                 txt_lyrics: None,
+                lrc_lyrics_source: None,
+                txt_lyrics_source: None,
+                instrumental: false,
             };
 
-            match downloader.download_for_track_with_fuzzy(&db_track, args.fuzzy_search).await {
+            let download = downloader.download_for_track_with_fuzzy(&db_track, args.fuzzy_search).await;
+
+            // Update the database with the attempt and lyrics information if we have a valid ID
+            if saved_track_id > 0 {
+                record_download_result(db, &db_track, &download).await;
+            }
+
+            match download {
                 Ok(lyrics_result) => {
-                    if lyrics_result.found {
+                    if lyrics_result.rejected {
+                        session.downloads_failed += 1;
+                        log_with_timestamp("WARN", &format!("MISMATCH {} - Rejected lyrics that do not fit the track length", truncate_path_for_log(file_path)));
+                    } else if lyrics_result.found {
                         session.downloads_successful += 1;
 
-                        // Update the database with lyrics information if we have a valid ID
-                        if saved_track_id > 0 {
-                            if let Err(e) = update_track_lyrics_in_db(db, saved_track_id, &lyrics_result).await {
-                                log_with_timestamp("WARN", &format!("DB_UPDATE_ERROR {} - Failed to update lyrics in database: {}", truncate_path_for_log(file_path), e));
-                            }
-                        }
-
                         if lyrics_result.synced_lyrics {
                             log_with_timestamp("INFO", &format!("SUCCESS {} - Downloaded synced lyrics", truncate_path_for_log(file_path)));
                         } else if lyrics_result.plain_lyrics {
@@ -525,15 +572,3 @@ async fn process_file(
 
     Ok(())
 }
-
-/// Helper function to update lyrics information in the database after download
-async fn update_track_lyrics_in_db(
-    _db: &mut Database,
-    _track_id: i64,
-    _lyrics_result: &crate::core::services::lrclib::LyricsDownloadResult,
-) -> anyhow::Result<()> {
-    // For now, we'll let the next scan pick up the lyrics files
-    // This is because the downloader saves files to disk but doesn't return the paths
-    // The database will be updated when the files are scanned again
-    Ok(())
-}
\ No newline at end of file
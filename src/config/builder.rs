@@ -1,12 +1,16 @@
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
 use directories::ProjectDirs;
 use tracing::warn;
-use crate::error::Result;
+use crate::error::{LrcGetError, Result};
+use crate::core::files::duration_check::DurationMismatchAction;
+use crate::core::files::lyrics::{parse_storage_overrides, LyricsStorage};
 use crate::config::validation::ConfigValidator;
 use crate::config::env::{EnvVars, EnvParser};
 use crate::config::Config;
 
 /// Configuration builder with validation and type safety
+#[derive(Debug)]
 pub struct ConfigBuilder {
     database_path: Option<PathBuf>,
     lrclib_instance: Option<String>,
@@ -18,6 +22,15 @@ pub struct ConfigBuilder {
     watch_debounce_seconds: Option<u64>,
     watch_batch_size: Option<usize>,
     redis_url: Option<Option<String>>,
+    lyrics_storage: Option<BTreeMap<String, LyricsStorage>>,
+    retry_schedule_days: Option<Vec<u32>>,
+    duration_mismatch_threshold_seconds: Option<f64>,
+    duration_mismatch_action: Option<DurationMismatchAction>,
+    lrclib_requests_per_second: Option<f64>,
+    lrclib_max_retries: Option<u32>,
+    negative_cache_ttl_hours: Option<u64>,
+    min_parallel_downloads: Option<usize>,
+    max_parallel_downloads: Option<usize>,
 }
 
 impl Default for ConfigBuilder {
@@ -40,6 +53,15 @@ impl ConfigBuilder {
             watch_debounce_seconds: None,
             watch_batch_size: None,
             redis_url: None,
+            lyrics_storage: None,
+            retry_schedule_days: None,
+            duration_mismatch_threshold_seconds: None,
+            duration_mismatch_action: None,
+            lrclib_requests_per_second: None,
+            lrclib_max_retries: None,
+            negative_cache_ttl_hours: None,
+            min_parallel_downloads: None,
+            max_parallel_downloads: None,
         }
     }
 
@@ -122,6 +144,102 @@ impl ConfigBuilder {
         Ok(self)
     }
 
+    /// Set per-format lyrics storage with validation
+    pub fn lyrics_storage(mut self, storage: BTreeMap<String, LyricsStorage>) -> Result<Self> {
+        let storage: BTreeMap<String, LyricsStorage> = storage
+            .into_iter()
+            .map(|(format, mode)| (format.trim_start_matches('.').to_lowercase(), mode))
+            .collect();
+        ConfigValidator::validate_lyrics_storage(&storage)?;
+        self.lyrics_storage = Some(storage);
+        Ok(self)
+    }
+
+    /// Set per-format lyrics storage from a `format=mode,...` list
+    pub fn lyrics_storage_spec(self, spec: &str) -> Result<Self> {
+        let storage = parse_storage_overrides(spec).map_err(LrcGetError::Validation)?;
+        self.lyrics_storage(storage)
+    }
+
+    /// Set the not-found retry schedule with validation
+    pub fn retry_schedule_days(mut self, days: Vec<u32>) -> Result<Self> {
+        ConfigValidator::validate_retry_schedule(&days)?;
+        self.retry_schedule_days = Some(days);
+        Ok(self)
+    }
+
+    /// Set the not-found retry schedule from a `1,7,30` list (a `d` suffix is accepted)
+    pub fn retry_schedule_spec(self, spec: &str) -> Result<Self> {
+        let days = spec
+            .split(',')
+            .map(str::trim)
+            .filter(|step| !step.is_empty())
+            .map(|step| {
+                step.trim_end_matches(['d', 'D']).parse::<u32>().map_err(|_| {
+                    LrcGetError::Validation(format!(
+                        "Invalid retry schedule step '{}': expected a number of days",
+                        step
+                    ))
+                })
+            })
+            .collect::<Result<Vec<u32>>>()?;
+        self.retry_schedule_days(days)
+    }
+
+    /// Set the duration mismatch threshold with validation
+    pub fn duration_mismatch_threshold_seconds(mut self, seconds: f64) -> Result<Self> {
+        ConfigValidator::validate_duration_threshold(seconds)?;
+        self.duration_mismatch_threshold_seconds = Some(seconds);
+        Ok(self)
+    }
+
+    /// Set what happens to lyrics whose length does not fit the track
+    pub fn duration_mismatch_action(mut self, action: DurationMismatchAction) -> Self {
+        self.duration_mismatch_action = Some(action);
+        self
+    }
+
+    /// Set the duration mismatch action from its name (flag, reject)
+    pub fn duration_mismatch_action_spec(self, spec: &str) -> Result<Self> {
+        let action = spec.parse::<DurationMismatchAction>().map_err(LrcGetError::Validation)?;
+        Ok(self.duration_mismatch_action(action))
+    }
+
+    /// Set the LRCLIB request rate with validation
+    pub fn lrclib_requests_per_second(mut self, requests_per_second: f64) -> Result<Self> {
+        ConfigValidator::validate_requests_per_second(requests_per_second)?;
+        self.lrclib_requests_per_second = Some(requests_per_second);
+        Ok(self)
+    }
+
+    /// Set the number of LRCLIB request retries with validation
+    pub fn lrclib_max_retries(mut self, retries: u32) -> Result<Self> {
+        ConfigValidator::validate_range(retries, 0, 10, "LRCLIB max retries")?;
+        self.lrclib_max_retries = Some(retries);
+        Ok(self)
+    }
+
+    /// Set how long not-found results stay cached with validation
+    pub fn negative_cache_ttl_hours(mut self, hours: u64) -> Result<Self> {
+        ConfigValidator::validate_range(hours, 1, 720, "negative cache TTL hours")?;
+        self.negative_cache_ttl_hours = Some(hours);
+        Ok(self)
+    }
+
+    /// Set the lower bound of adaptive download concurrency with validation
+    pub fn min_parallel_downloads(mut self, downloads: usize) -> Result<Self> {
+        ConfigValidator::validate_range(downloads, 1, 64, "min parallel downloads")?;
+        self.min_parallel_downloads = Some(downloads);
+        Ok(self)
+    }
+
+    /// Set the upper bound of adaptive download concurrency with validation
+    pub fn max_parallel_downloads(mut self, downloads: usize) -> Result<Self> {
+        ConfigValidator::validate_range(downloads, 1, 64, "max parallel downloads")?;
+        self.max_parallel_downloads = Some(downloads);
+        Ok(self)
+    }
+
     /// Load values from environment variables with validation
     pub fn load_from_env(mut self) -> Result<Self> {
         // Database path
@@ -171,6 +289,48 @@ impl ConfigBuilder {
             self = self.redis_url(Some(redis))?;
         }
 
+        // Per-format lyrics storage
+        if let Some(spec) = EnvParser::parse_string(EnvVars::LYRICS_STORAGE, None)? {
+            self = self.lyrics_storage_spec(&spec)?;
+        }
+
+        // Not-found retry schedule
+        if let Some(spec) = EnvParser::parse_string(EnvVars::RETRY_SCHEDULE_DAYS, None)? {
+            self = self.retry_schedule_spec(&spec)?;
+        }
+
+        // Duration mismatch handling
+        if let Some(seconds) = EnvParser::parse_f64(EnvVars::DURATION_MISMATCH_THRESHOLD_SECONDS, 1.0, 600.0)? {
+            self = self.duration_mismatch_threshold_seconds(seconds)?;
+        }
+
+        if let Some(action) = EnvParser::parse_string(EnvVars::DURATION_MISMATCH_ACTION, None)? {
+            self = self.duration_mismatch_action_spec(&action)?;
+        }
+
+        // LRCLIB rate limiting
+        if let Some(requests_per_second) = EnvParser::parse_f64(EnvVars::LRCLIB_REQUESTS_PER_SECOND, 0.1, 100.0)? {
+            self = self.lrclib_requests_per_second(requests_per_second)?;
+        }
+
+        if let Some(retries) = EnvParser::parse_u64(EnvVars::LRCLIB_MAX_RETRIES, 0, 10)? {
+            self = self.lrclib_max_retries(retries as u32)?;
+        }
+
+        // Not-found cache lifetime
+        if let Some(hours) = EnvParser::parse_u64(EnvVars::NEGATIVE_CACHE_TTL_HOURS, 1, 720)? {
+            self = self.negative_cache_ttl_hours(hours)?;
+        }
+
+        // Adaptive download concurrency bounds
+        if let Some(downloads) = EnvParser::parse_usize(EnvVars::MIN_PARALLEL_DOWNLOADS, 1, 64)? {
+            self = self.min_parallel_downloads(downloads)?;
+        }
+
+        if let Some(downloads) = EnvParser::parse_usize(EnvVars::MAX_PARALLEL_DOWNLOADS, 1, 64)? {
+            self = self.max_parallel_downloads(downloads)?;
+        }
+
         Ok(self)
     }
 
@@ -199,6 +359,24 @@ impl ConfigBuilder {
                 .unwrap_or(50),
             redis_url: self.redis_url
                 .unwrap_or(None),
+            lyrics_storage: self.lyrics_storage
+                .unwrap_or_default(),
+            retry_schedule_days: self.retry_schedule_days
+                .unwrap_or_else(|| vec![1, 7, 30]),
+            duration_mismatch_threshold_seconds: self.duration_mismatch_threshold_seconds
+                .unwrap_or(10.0),
+            duration_mismatch_action: self.duration_mismatch_action
+                .unwrap_or_default(),
+            lrclib_requests_per_second: self.lrclib_requests_per_second
+                .unwrap_or(5.0),
+            lrclib_max_retries: self.lrclib_max_retries
+                .unwrap_or(3),
+            negative_cache_ttl_hours: self.negative_cache_ttl_hours
+                .unwrap_or(24),
+            min_parallel_downloads: self.min_parallel_downloads
+                .unwrap_or(1),
+            max_parallel_downloads: self.max_parallel_downloads
+                .unwrap_or(16),
         };
 
         // Final validation
@@ -258,6 +436,20 @@ impl Config {
             ConfigValidator::validate_redis_url(url)?;
         }
 
+        ConfigValidator::validate_lyrics_storage(&self.lyrics_storage)?;
+
+        ConfigValidator::validate_retry_schedule(&self.retry_schedule_days)?;
+
+        ConfigValidator::validate_duration_threshold(self.duration_mismatch_threshold_seconds)?;
+
+        ConfigValidator::validate_requests_per_second(self.lrclib_requests_per_second)?;
+
+        ConfigValidator::validate_range(self.lrclib_max_retries, 0, 10, "LRCLIB max retries")?;
+
+        ConfigValidator::validate_range(self.negative_cache_ttl_hours, 1, 720, "negative cache TTL hours")?;
+
+        ConfigValidator::validate_parallel_downloads(self.min_parallel_downloads, self.max_parallel_downloads)?;
+
         Ok(())
     }
 }
@@ -265,6 +457,7 @@ impl Config {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::LrcGetError;
     use std::env;
 
     #[test]
@@ -306,4 +499,43 @@ mod tests {
         env::remove_var("LRCGET_LRCLIB_INSTANCE");
         env::remove_var("LRCGET_SKIP_TRACKS_WITH_SYNCED_LYRICS");
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_config_builder_lyrics_storage() {
+        let config = ConfigBuilder::new()
+            .try_embed_lyrics(true)
+            .lyrics_storage_spec("M4A=embed, .flac=sidecar")
+            .unwrap()
+            .build()
+            .unwrap();
+
+        let policy = config.lyrics_storage_policy();
+        assert_eq!(policy.for_path("/music/a.m4a"), LyricsStorage::Embed);
+        assert_eq!(policy.for_path("/music/b.flac"), LyricsStorage::Sidecar);
+        assert_eq!(policy.for_path("/music/c.mp3"), LyricsStorage::Both);
+        assert_eq!(policy.for_path("/music/d.wav"), LyricsStorage::Sidecar);
+
+        let serialized = toml::to_string_pretty(&config).unwrap();
+        let reloaded: Config = toml::from_str(&serialized).unwrap();
+        assert_eq!(reloaded.lyrics_storage, config.lyrics_storage);
+
+        assert!(ConfigBuilder::new().lyrics_storage_spec("wav=embed").is_err());
+        assert!(ConfigBuilder::new().lyrics_storage_spec("mp3=sometimes").is_err());
+    }
+
+    #[test]
+    fn test_config_builder_retry_schedule() {
+        let config = ConfigBuilder::new().build().unwrap();
+        assert_eq!(config.retry_schedule_days, vec![1, 7, 30]);
+
+        let config = ConfigBuilder::new()
+            .retry_schedule_spec("2d, 14, 60d")
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!(config.retry_schedule_days, vec![2, 14, 60]);
+
+        assert!(ConfigBuilder::new().retry_schedule_spec("1,week").is_err());
+        assert!(ConfigBuilder::new().retry_schedule_spec("0,7").is_err());
+    }
+}
@@ -16,6 +16,15 @@ impl EnvVars {
     pub const WATCH_DEBOUNCE_SECONDS: &'static str = "LRCGET_WATCH_DEBOUNCE_SECONDS";
     pub const WATCH_BATCH_SIZE: &'static str = "LRCGET_WATCH_BATCH_SIZE";
     pub const REDIS_URL: &'static str = "LRCGET_REDIS_URL";
+    pub const LYRICS_STORAGE: &'static str = "LRCGET_LYRICS_STORAGE";
+    pub const RETRY_SCHEDULE_DAYS: &'static str = "LRCGET_RETRY_SCHEDULE_DAYS";
+    pub const DURATION_MISMATCH_THRESHOLD_SECONDS: &'static str = "LRCGET_DURATION_MISMATCH_THRESHOLD_SECONDS";
+    pub const DURATION_MISMATCH_ACTION: &'static str = "LRCGET_DURATION_MISMATCH_ACTION";
+    pub const LRCLIB_REQUESTS_PER_SECOND: &'static str = "LRCGET_LRCLIB_REQUESTS_PER_SECOND";
+    pub const LRCLIB_MAX_RETRIES: &'static str = "LRCGET_LRCLIB_MAX_RETRIES";
+    pub const NEGATIVE_CACHE_TTL_HOURS: &'static str = "LRCGET_NEGATIVE_CACHE_TTL_HOURS";
+    pub const MIN_PARALLEL_DOWNLOADS: &'static str = "LRCGET_MIN_PARALLEL_DOWNLOADS";
+    pub const MAX_PARALLEL_DOWNLOADS: &'static str = "LRCGET_MAX_PARALLEL_DOWNLOADS";
 
     // Special environment variables
     pub const DOCKER: &'static str = "DOCKER";
@@ -134,6 +143,29 @@ impl EnvParser {
         }
     }
 
+    /// Parse environment variable as f64 with range validation
+    pub fn parse_f64(var_name: &str, min: f64, max: f64) -> Result<Option<f64>> {
+        if let Some(value_str) = Self::parse_string(var_name, None)? {
+            let value = value_str.parse::<f64>().map_err(|_| {
+                LrcGetError::Validation(format!(
+                    "Invalid number in {}: '{}'. Must be a number",
+                    var_name, value_str
+                ))
+            })?;
+
+            if !(min..=max).contains(&value) {
+                return Err(LrcGetError::Validation(format!(
+                    "Value in {} must be between {} and {}, got {}",
+                    var_name, min, max, value
+                )));
+            }
+
+            Ok(Some(value))
+        } else {
+            Ok(None)
+        }
+    }
+
     /// Check if environment variable is present (for boolean flags)
     pub fn is_present(var_name: &str) -> bool {
         env::var(var_name).is_ok()
@@ -1,7 +1,11 @@
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::{Path, PathBuf};
 use crate::error::Result;
+use crate::core::files::duration_check::{DurationMismatchAction, DurationPolicy};
+use crate::core::files::lyrics::{LyricsStorage, LyricsStoragePolicy};
+use crate::core::services::rate_limit::RateLimitPolicy;
 
 pub mod validation;
 pub mod env;
@@ -17,6 +21,34 @@ fn default_watch_batch_size() -> usize {
     50
 }
 
+fn default_retry_schedule_days() -> Vec<u32> {
+    vec![1, 7, 30]
+}
+
+fn default_duration_mismatch_threshold_seconds() -> f64 {
+    10.0
+}
+
+fn default_lrclib_requests_per_second() -> f64 {
+    5.0
+}
+
+fn default_lrclib_max_retries() -> u32 {
+    3
+}
+
+fn default_negative_cache_ttl_hours() -> u64 {
+    24
+}
+
+fn default_min_parallel_downloads() -> usize {
+    1
+}
+
+fn default_max_parallel_downloads() -> usize {
+    16
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     /// Database file path
@@ -51,6 +83,44 @@ pub struct Config {
     /// Redis URL for cache (optional)
     #[serde(default)]
     pub redis_url: Option<String>,
+
+    /// Per-format lyrics storage (sidecar, embed, both) used when `try_embed_lyrics` is set.
+    /// Formats without an entry get both a sidecar and embedded tags.
+    #[serde(default)]
+    pub lyrics_storage: BTreeMap<String, LyricsStorage>,
+
+    /// Days to wait before re-checking a track whose lyrics were not found, indexed by the
+    /// number of consecutive misses. The last entry applies to every miss after that.
+    #[serde(default = "default_retry_schedule_days")]
+    pub retry_schedule_days: Vec<u32>,
+
+    /// Seconds the lyrics length may differ from the audio file before it counts as a mismatch
+    #[serde(default = "default_duration_mismatch_threshold_seconds")]
+    pub duration_mismatch_threshold_seconds: f64,
+
+    /// What to do with mismatched lyrics when saving (flag, reject)
+    #[serde(default)]
+    pub duration_mismatch_action: DurationMismatchAction,
+
+    /// Requests per second to LRCLIB, shared by every download running in the process
+    #[serde(default = "default_lrclib_requests_per_second")]
+    pub lrclib_requests_per_second: f64,
+
+    /// Retries of an LRCLIB request after a 429, 5xx or network error
+    #[serde(default = "default_lrclib_max_retries")]
+    pub lrclib_max_retries: u32,
+
+    /// Hours a "no lyrics on LRCLIB" answer is cached before the track is looked up again
+    #[serde(default = "default_negative_cache_ttl_hours")]
+    pub negative_cache_ttl_hours: u64,
+
+    /// Lowest number of parallel downloads the adaptive limit may drop to
+    #[serde(default = "default_min_parallel_downloads")]
+    pub min_parallel_downloads: usize,
+
+    /// Highest number of parallel downloads the adaptive limit may grow to
+    #[serde(default = "default_max_parallel_downloads")]
+    pub max_parallel_downloads: usize,
 }
 
 impl Default for Config {
@@ -71,6 +141,15 @@ impl Default for Config {
                     watch_debounce_seconds: 10,
                     watch_batch_size: 50,
                     redis_url: None,
+                    lyrics_storage: BTreeMap::new(),
+                    retry_schedule_days: default_retry_schedule_days(),
+                    duration_mismatch_threshold_seconds: default_duration_mismatch_threshold_seconds(),
+                    duration_mismatch_action: DurationMismatchAction::Flag,
+                    lrclib_requests_per_second: default_lrclib_requests_per_second(),
+                    lrclib_max_retries: default_lrclib_max_retries(),
+                    negative_cache_ttl_hours: default_negative_cache_ttl_hours(),
+                    min_parallel_downloads: default_min_parallel_downloads(),
+                    max_parallel_downloads: default_max_parallel_downloads(),
                 }
             })
     }
@@ -117,7 +196,16 @@ impl Config {
                 .show_line_count(file_config.show_line_count)
                 .watch_debounce_seconds(file_config.watch_debounce_seconds)?
                 .watch_batch_size(file_config.watch_batch_size)?
-                .redis_url(file_config.redis_url)?;
+                .redis_url(file_config.redis_url)?
+                .lyrics_storage(file_config.lyrics_storage)?
+                .retry_schedule_days(file_config.retry_schedule_days)?
+                .duration_mismatch_threshold_seconds(file_config.duration_mismatch_threshold_seconds)?
+                .duration_mismatch_action(file_config.duration_mismatch_action)
+                .lrclib_requests_per_second(file_config.lrclib_requests_per_second)?
+                .lrclib_max_retries(file_config.lrclib_max_retries)?
+                .negative_cache_ttl_hours(file_config.negative_cache_ttl_hours)?
+                .min_parallel_downloads(file_config.min_parallel_downloads)?
+                .max_parallel_downloads(file_config.max_parallel_downloads)?;
         }
 
         // Override with environment variables (highest priority)
@@ -193,6 +281,20 @@ impl Config {
             .join("lrclib.db")
     }
 
+    /// Directory of the file lyrics cache, next to the library database
+    pub fn cache_dir(&self) -> PathBuf {
+        self.database_path.parent()
+            .unwrap_or(&self.database_path)
+            .join("cache")
+    }
+
+    /// Lyrics cache (files, plus Redis when `redis_url` is set) with the configured not-found TTL
+    pub fn create_lyrics_cache(&self) -> Result<crate::core::infrastructure::cache::LyricsCache> {
+        let cache = crate::core::infrastructure::cache::LyricsCache::new(self.cache_dir(), self.redis_url.as_deref())?
+            .with_negative_ttl_hours(self.negative_cache_ttl_hours);
+        Ok(cache)
+    }
+
     pub fn create_lrclib_client(&self) -> crate::core::services::lrclib::LrclibClient {
         if let Some(ref local_db_path) = self.lrclib_database_path {
             crate::core::services::lrclib::LrclibClient::with_local_db(&self.lrclib_instance, local_db_path)
@@ -201,6 +303,44 @@ impl Config {
         }
     }
 
+    /// Downloader with the configured client and storage/duration policies. `cache` is the
+    /// shared cache from `create_lyrics_cache`; only saving lyrics the user picked skips it.
+    pub fn create_lyrics_downloader(
+        &self,
+        cache: Option<std::sync::Arc<tokio::sync::RwLock<crate::core::infrastructure::cache::LyricsCache>>>,
+    ) -> crate::core::services::lrclib::LyricsDownloader {
+        use crate::core::services::lrclib::LyricsDownloader;
+
+        let client = self.create_lrclib_client();
+        let downloader = match cache {
+            Some(cache) => LyricsDownloader::from_client_with_cache(client, cache),
+            None => LyricsDownloader::from_client(client),
+        };
+        downloader
+            .with_storage_policy(self.lyrics_storage_policy())
+            .with_duration_policy(self.duration_policy())
+    }
+
+    /// Storage policy for downloaded lyrics, combining `try_embed_lyrics` and `lyrics_storage`
+    pub fn lyrics_storage_policy(&self) -> LyricsStoragePolicy {
+        LyricsStoragePolicy::new(self.try_embed_lyrics, self.lyrics_storage.clone())
+    }
+
+    pub fn duration_policy(&self) -> DurationPolicy {
+        DurationPolicy {
+            threshold_seconds: self.duration_mismatch_threshold_seconds,
+            action: self.duration_mismatch_action,
+        }
+    }
+
+    /// Rate and retry budget for LRCLIB requests
+    pub fn rate_limit_policy(&self) -> RateLimitPolicy {
+        RateLimitPolicy {
+            requests_per_second: self.lrclib_requests_per_second,
+            max_retries: self.lrclib_max_retries,
+        }
+    }
+
     pub fn create_lrclib_client_no_local_db(&self) -> crate::core::services::lrclib::LrclibClient {
         crate::core::services::lrclib::LrclibClient::new(&self.lrclib_instance)
     }
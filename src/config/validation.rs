@@ -1,6 +1,8 @@
+use std::collections::BTreeMap;
 use std::path::Path;
 use url::Url;
 use crate::error::{Result, LrcGetError};
+use crate::core::files::lyrics::{LyricsStorage, EMBEDDABLE_FORMATS};
 
 /// Centralized configuration validation utilities
 pub struct ConfigValidator;
@@ -66,6 +68,71 @@ impl ConfigValidator {
         Ok(())
     }
 
+    /// Validate per-format lyrics storage entries
+    pub fn validate_lyrics_storage(storage: &BTreeMap<String, LyricsStorage>) -> Result<()> {
+        for (format, mode) in storage {
+            if mode.embeds() && !EMBEDDABLE_FORMATS.contains(&format.as_str()) {
+                return Err(LrcGetError::Validation(format!(
+                    "Lyrics storage '{}' is not available for '{}'. Embedding supports: {}",
+                    mode,
+                    format,
+                    EMBEDDABLE_FORMATS.join(", ")
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Validate the not-found retry schedule
+    pub fn validate_retry_schedule(days: &[u32]) -> Result<()> {
+        if days.len() > 10 {
+            return Err(LrcGetError::Validation(format!(
+                "Retry schedule can have at most 10 steps, got {}",
+                days.len()
+            )));
+        }
+
+        for &step in days {
+            Self::validate_range(step, 1, 3650, "retry schedule days")?;
+        }
+        Ok(())
+    }
+
+    /// Validate the duration mismatch threshold
+    pub fn validate_duration_threshold(seconds: f64) -> Result<()> {
+        if !seconds.is_finite() {
+            return Err(LrcGetError::Validation(format!(
+                "Duration mismatch threshold must be a number, got {}",
+                seconds
+            )));
+        }
+        Self::validate_range(seconds, 1.0, 600.0, "duration mismatch threshold seconds")
+    }
+
+    /// Validate the LRCLIB request rate
+    pub fn validate_requests_per_second(requests_per_second: f64) -> Result<()> {
+        if !requests_per_second.is_finite() {
+            return Err(LrcGetError::Validation(format!(
+                "LRCLIB requests per second must be a number, got {}",
+                requests_per_second
+            )));
+        }
+        Self::validate_range(requests_per_second, 0.1, 100.0, "LRCLIB requests per second")
+    }
+
+    /// Validate the adaptive download concurrency bounds
+    pub fn validate_parallel_downloads(min: usize, max: usize) -> Result<()> {
+        Self::validate_range(min, 1, 64, "min parallel downloads")?;
+        Self::validate_range(max, 1, 64, "max parallel downloads")?;
+        if min > max {
+            return Err(LrcGetError::Validation(format!(
+                "min parallel downloads ({}) cannot be greater than max parallel downloads ({})",
+                min, max
+            )));
+        }
+        Ok(())
+    }
+
     /// Validate database file extension
     pub fn validate_db_path(path: &Path) -> Result<()> {
         if let Some(ext) = path.extension() {
@@ -5,13 +5,18 @@
 
 use anyhow::Result;
 use rusqlite::{Connection, params};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use crate::core::files::scanner::Track;
+use crate::core::files::scanner::{FileFingerprint, LyricsSource, Track};
 
-const CURRENT_DB_VERSION: u32 = 1;
+const CURRENT_DB_VERSION: u32 = 6;
+
+/// Column list shared by every query that maps rows with `track_from_row`
+const TRACK_COLUMNS: &str = "id, file_path, file_name, title, album, artist, album_artist, \
+    duration, track_number, txt_lyrics, lrc_lyrics, txt_lyrics_source, lrc_lyrics_source, instrumental";
 
 pub struct Database {
     conn: Connection,
@@ -49,7 +54,7 @@ impl Database {
         if existing_version == 0 {
             let tx = conn.transaction()?;
 
-            tx.pragma_update(None, "user_version", CURRENT_DB_VERSION)?;
+            tx.pragma_update(None, "user_version", 1)?;
 
             tx.execute_batch(r#"
                 CREATE TABLE directories (
@@ -88,6 +93,100 @@ impl Database {
             tx.commit()?;
         }
 
+        if existing_version < 2 {
+            let tx = conn.transaction()?;
+
+            tx.pragma_update(None, "user_version", 2)?;
+
+            // Where txt/lrc lyrics were found: 'sidecar' or 'embedded'
+            tx.execute_batch(r#"
+                ALTER TABLE tracks ADD COLUMN txt_lyrics_source TEXT;
+                ALTER TABLE tracks ADD COLUMN lrc_lyrics_source TEXT;
+
+                UPDATE tracks SET txt_lyrics_source = 'sidecar' WHERE txt_lyrics IS NOT NULL;
+                UPDATE tracks SET lrc_lyrics_source = 'sidecar' WHERE lrc_lyrics IS NOT NULL;
+            "#)?;
+
+            tx.commit()?;
+        }
+
+        if existing_version < 3 {
+            let tx = conn.transaction()?;
+
+            tx.pragma_update(None, "user_version", 3)?;
+
+            // File fingerprints for incremental scanning
+            tx.execute_batch(r#"
+                ALTER TABLE tracks ADD COLUMN file_mtime INTEGER;
+                ALTER TABLE tracks ADD COLUMN file_size INTEGER;
+                ALTER TABLE tracks ADD COLUMN sidecar_fingerprint TEXT;
+                ALTER TABLE tracks ADD COLUMN content_hash TEXT;
+            "#)?;
+
+            tx.commit()?;
+        }
+
+        if existing_version < 4 {
+            let tx = conn.transaction()?;
+
+            tx.pragma_update(None, "user_version", 4)?;
+
+            // Latest download outcome per track plus the full attempt log
+            tx.execute_batch(r#"
+                CREATE TABLE download_status (
+                    track_id INTEGER PRIMARY KEY,
+                    last_attempt_at INTEGER NOT NULL,
+                    outcome TEXT NOT NULL,
+                    source TEXT,
+                    lrclib_id INTEGER,
+                    consecutive_misses INTEGER NOT NULL DEFAULT 0
+                );
+
+                CREATE TABLE download_attempts (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    track_id INTEGER NOT NULL,
+                    attempted_at INTEGER NOT NULL,
+                    outcome TEXT NOT NULL,
+                    source TEXT,
+                    lrclib_id INTEGER,
+                    error TEXT
+                );
+
+                CREATE INDEX idx_download_attempts_track ON download_attempts(track_id);
+            "#)?;
+
+            tx.commit()?;
+        }
+
+        if existing_version < 5 {
+            let tx = conn.transaction()?;
+
+            tx.pragma_update(None, "user_version", 5)?;
+
+            // Instrumental tracks were stored with their marker as synced lyrics
+            tx.execute_batch(r#"
+                ALTER TABLE tracks ADD COLUMN instrumental BOOLEAN NOT NULL DEFAULT 0;
+
+                UPDATE tracks SET instrumental = 1, lrc_lyrics = NULL, lrc_lyrics_source = NULL
+                WHERE lower(trim(lrc_lyrics)) = '[au: instrumental]';
+            "#)?;
+
+            tx.commit()?;
+        }
+
+        if existing_version < 6 {
+            let tx = conn.transaction()?;
+
+            tx.pragma_update(None, "user_version", 6)?;
+
+            tx.execute_batch(r#"
+                ALTER TABLE download_attempts ADD COLUMN confidence REAL;
+                ALTER TABLE download_status ADD COLUMN confidence REAL;
+            "#)?;
+
+            tx.commit()?;
+        }
+
         info!("Database upgraded successfully");
         Ok(())
     }
@@ -123,83 +222,227 @@ impl Database {
         Ok(())
     }
 
+    /// Remove tracks by file path, returning how many rows were deleted
+    pub async fn remove_tracks(&mut self, file_paths: &[String]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut removed = 0;
+
+        {
+            let mut delete_history = tx.prepare(
+                "DELETE FROM download_attempts WHERE track_id IN (SELECT id FROM tracks WHERE file_path = ?1)"
+            )?;
+            let mut delete_status = tx.prepare(
+                "DELETE FROM download_status WHERE track_id IN (SELECT id FROM tracks WHERE file_path = ?1)"
+            )?;
+            let mut delete_track = tx.prepare("DELETE FROM tracks WHERE file_path = ?1")?;
+            for file_path in file_paths {
+                delete_history.execute(params![file_path])?;
+                delete_status.execute(params![file_path])?;
+                removed += delete_track.execute(params![file_path])?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(removed)
+    }
+
+    /// Point an existing track row at a new file path, keeping its id
+    pub async fn move_track(&mut self, old_path: &str, new_path: &str) -> Result<()> {
+        let file_name = Path::new(new_path)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| new_path.to_string());
+
+        self.conn.execute(
+            "UPDATE tracks SET file_path = ?1, file_name = ?2, updated_at = CURRENT_TIMESTAMP WHERE file_path = ?3",
+            params![new_path, file_name, old_path],
+        )?;
+        Ok(())
+    }
+
     pub async fn add_track(&mut self, track: &Track) -> Result<()> {
+        upsert_track(&self.conn, track)?;
+        Ok(())
+    }
+
+    /// Insert or update several tracks in a single transaction
+    pub async fn add_tracks(&mut self, tracks: &[Track]) -> Result<usize> {
+        let tx = self.conn.transaction()?;
+        let mut added = 0;
+
+        for track in tracks {
+            match upsert_track(&tx, track) {
+                Ok(()) => added += 1,
+                Err(e) => warn!("Failed to add track {}: {}", track.file_path, e),
+            }
+        }
+
+        tx.commit()?;
+        Ok(added)
+    }
+
+    /// Fingerprints of every known file, keyed by file path
+    pub async fn get_file_states(&self) -> Result<HashMap<String, StoredFileState>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT file_path, file_mtime, file_size, sidecar_fingerprint, content_hash FROM tracks"
+        )?;
+
+        let states = stmt.query_map([], |row| {
+            let file_path: String = row.get(0)?;
+            let mtime: Option<i64> = row.get(1)?;
+            let size: Option<i64> = row.get(2)?;
+            let sidecars: Option<String> = row.get(3)?;
+            let content_hash: Option<String> = row.get(4)?;
+
+            // Rows scanned before fingerprints existed always count as changed
+            let fingerprint = match (mtime, size, sidecars) {
+                (Some(mtime), Some(size), Some(sidecars)) => Some(FileFingerprint { mtime, size, sidecars }),
+                _ => None,
+            };
+
+            Ok((file_path, StoredFileState { fingerprint, content_hash }))
+        })?
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(states)
+    }
+
+    /// Record a new fingerprint for a file whose contents are known to be unchanged
+    pub async fn update_file_fingerprint(&mut self, file_path: &str, fingerprint: &FileFingerprint) -> Result<()> {
         self.conn.execute(
+            "UPDATE tracks SET file_mtime = ?1, file_size = ?2, sidecar_fingerprint = ?3 WHERE file_path = ?4",
+            params![fingerprint.mtime, fingerprint.size, fingerprint.sidecars, file_path],
+        )?;
+        Ok(())
+    }
+
+    /// Record a download attempt and update the track's current download status
+    pub async fn record_download_attempt(&mut self, attempt: &DownloadAttempt) -> Result<()> {
+        let tx = self.conn.transaction()?;
+        let attempted_at = chrono::Utc::now().timestamp();
+
+        tx.execute(
             r#"
-            INSERT OR REPLACE INTO tracks 
-            (file_path, file_name, title, album, artist, album_artist, duration, track_number, txt_lyrics, lrc_lyrics, updated_at)
-            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, CURRENT_TIMESTAMP)
+            INSERT INTO download_attempts (track_id, attempted_at, outcome, source, lrclib_id, error, confidence)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
             "#,
             params![
-                track.file_path,
-                track.file_name,
-                track.title,
-                track.album,
-                track.artist,
-                track.album_artist,
-                track.duration,
-                track.track_number,
-                track.txt_lyrics,
-                track.lrc_lyrics,
+                attempt.track_id,
+                attempted_at,
+                attempt.outcome.as_str(),
+                attempt.source,
+                attempt.lrclib_id,
+                attempt.error,
+                attempt.confidence,
             ],
         )?;
+
+        // Errors are transient and leave the miss streak untouched; rejected
+        // lyrics count as a miss since nothing usable was found
+        let counts_as_miss = matches!(attempt.outcome, DownloadOutcome::NotFound | DownloadOutcome::Mismatch);
+        let misses_update = match attempt.outcome {
+            DownloadOutcome::NotFound | DownloadOutcome::Mismatch => "download_status.consecutive_misses + 1",
+            DownloadOutcome::Error => "download_status.consecutive_misses",
+            _ => "0",
+        };
+
+        tx.execute(
+            &format!(
+                r#"
+                INSERT INTO download_status (track_id, last_attempt_at, outcome, source, lrclib_id, consecutive_misses, confidence)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+                ON CONFLICT(track_id) DO UPDATE SET
+                    last_attempt_at = excluded.last_attempt_at,
+                    outcome = excluded.outcome,
+                    source = excluded.source,
+                    lrclib_id = excluded.lrclib_id,
+                    consecutive_misses = {},
+                    confidence = excluded.confidence
+                "#,
+                misses_update
+            ),
+            params![
+                attempt.track_id,
+                attempted_at,
+                attempt.outcome.as_str(),
+                attempt.source,
+                attempt.lrclib_id,
+                if counts_as_miss { 1 } else { 0 },
+                attempt.confidence,
+            ],
+        )?;
+
+        tx.commit()?;
         Ok(())
     }
 
+    /// Current download status of every track that has been attempted, keyed by track id
+    pub async fn get_download_statuses(&self) -> Result<HashMap<i64, DownloadStatus>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT track_id, last_attempt_at, outcome, source, lrclib_id, consecutive_misses, confidence FROM download_status"
+        )?;
+
+        let statuses = stmt.query_map([], download_status_from_row)?
+        .map(|status| status.map(|status| (status.track_id, status)))
+        .collect::<Result<HashMap<_, _>, _>>()?;
+
+        Ok(statuses)
+    }
+
+    /// Current download status of one track, if it has been attempted
+    pub async fn get_download_status(&self, track_id: i64) -> Result<Option<DownloadStatus>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT track_id, last_attempt_at, outcome, source, lrclib_id, consecutive_misses, confidence
+             FROM download_status WHERE track_id = ?1"
+        )?;
+
+        match stmt.query_row(params![track_id], download_status_from_row) {
+            Ok(status) => Ok(Some(status)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn get_track(&self, id: i64) -> Result<DatabaseTrack> {
-        let mut stmt = self.conn.prepare(r#"
-            SELECT id, file_path, file_name, title, album, artist, album_artist, 
-                   duration, track_number, txt_lyrics, lrc_lyrics
-            FROM tracks WHERE id = ?1
-        "#)?;
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tracks WHERE id = ?1",
+            TRACK_COLUMNS
+        ))?;
         
-        let track = stmt.query_row(params![id], |row| {
-            Ok(DatabaseTrack {
-                id: row.get(0)?,
-                file_path: row.get(1)?,
-                file_name: row.get(2)?,
-                title: row.get(3)?,
-                album_name: row.get(4)?,
-                artist_name: row.get(5)?,
-                album_artist: row.get(6)?,
-                duration: row.get(7)?,
-                track_number: row.get(8)?,
-                txt_lyrics: row.get(9)?,
-                lrc_lyrics: row.get(10)?,
-            })
-        })?;
+        let track = stmt.query_row(params![id], track_from_row)?;
 
         Ok(track)
     }
 
     pub async fn get_all_tracks(&self) -> Result<Vec<DatabaseTrack>> {
-        let mut stmt = self.conn.prepare(r#"
-            SELECT id, file_path, file_name, title, album, artist, album_artist,
-                   duration, track_number, txt_lyrics, lrc_lyrics
-            FROM tracks 
-            ORDER BY artist, album, track_number, title
-        "#)?;
-
-        let tracks = stmt.query_map([], |row| {
-            Ok(DatabaseTrack {
-                id: row.get(0)?,
-                file_path: row.get(1)?,
-                file_name: row.get(2)?,
-                title: row.get(3)?,
-                album_name: row.get(4)?,
-                artist_name: row.get(5)?,
-                album_artist: row.get(6)?,
-                duration: row.get(7)?,
-                track_number: row.get(8)?,
-                txt_lyrics: row.get(9)?,
-                lrc_lyrics: row.get(10)?,
-            })
-        })?
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tracks ORDER BY artist, album, track_number, title",
+            TRACK_COLUMNS
+        ))?;
+
+        let tracks = stmt.query_map([], track_from_row)?
         .collect::<Result<Vec<DatabaseTrack>, _>>()?;
 
         Ok(tracks)
     }
 
+    /// First library track matching title and artist (and album, if given), ignoring case
+    pub async fn find_track(&self, title: &str, artist: &str, album: Option<&str>) -> Result<Option<DatabaseTrack>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tracks
+             WHERE lower(title) = lower(?1) AND lower(artist) = lower(?2)
+               AND (?3 IS NULL OR lower(album) = lower(?3))
+             ORDER BY id LIMIT 1",
+            TRACK_COLUMNS
+        ))?;
+
+        match stmt.query_row(params![title.trim(), artist.trim(), album.map(str::trim)], track_from_row) {
+            Ok(track) => Ok(Some(track)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn track_exists(&self, file_path: &std::path::Path) -> Result<bool> {
         let mut stmt = self.conn.prepare("SELECT 1 FROM tracks WHERE file_path = ?1")?;
         let result = stmt.query_row(params![file_path.to_string_lossy()], |_| Ok(()));
@@ -211,29 +454,27 @@ impl Database {
         }
     }
 
+    /// Library track stored under this exact path, if any
+    pub async fn find_track_by_path(&self, file_path: &str) -> Result<Option<DatabaseTrack>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tracks WHERE file_path = ?1",
+            TRACK_COLUMNS
+        ))?;
+
+        match stmt.query_row(params![file_path], track_from_row) {
+            Ok(track) => Ok(Some(track)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub async fn get_track_by_path(&self, file_path: &str) -> Result<DatabaseTrack> {
-        let mut stmt = self.conn.prepare(r#"
-            SELECT id, file_path, file_name, title, album, artist, album_artist,
-                   duration, track_number, txt_lyrics, lrc_lyrics
-            FROM tracks 
-            WHERE file_path = ?1
-        "#)?;
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM tracks WHERE file_path = ?1",
+            TRACK_COLUMNS
+        ))?;
         
-        let track = stmt.query_row(params![file_path], |row| {
-            Ok(DatabaseTrack {
-                id: row.get(0)?,
-                file_path: row.get(1)?,
-                file_name: row.get(2)?,
-                title: row.get(3)?,
-                album_name: row.get(4)?,
-                artist_name: row.get(5)?,
-                album_artist: row.get(6)?,
-                duration: row.get(7)?,
-                track_number: row.get(8)?,
-                txt_lyrics: row.get(9)?,
-                lrc_lyrics: row.get(10)?,
-            })
-        })?;
+        let track = stmt.query_row(params![file_path], track_from_row)?;
 
         Ok(track)
     }
@@ -252,4 +493,293 @@ pub struct DatabaseTrack {
     pub track_number: Option<i64>,
     pub txt_lyrics: Option<String>,
     pub lrc_lyrics: Option<String>,
-}
\ No newline at end of file
+    pub txt_lyrics_source: Option<LyricsSource>,
+    pub lrc_lyrics_source: Option<LyricsSource>,
+    pub instrumental: bool,
+}
+
+impl DatabaseTrack {
+    /// Whether the track has neither lyrics nor an instrumental marker
+    pub fn is_missing_lyrics(&self) -> bool {
+        self.lrc_lyrics.is_none() && self.txt_lyrics.is_none() && !self.instrumental
+    }
+}
+
+/// Result of a single lyrics download attempt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DownloadOutcome {
+    Synced,
+    Plain,
+    Instrumental,
+    NotFound,
+    /// Lyrics found but rejected because their length does not fit the track
+    Mismatch,
+    Error,
+}
+
+impl DownloadOutcome {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DownloadOutcome::Synced => "synced",
+            DownloadOutcome::Plain => "plain",
+            DownloadOutcome::Instrumental => "instrumental",
+            DownloadOutcome::NotFound => "not_found",
+            DownloadOutcome::Mismatch => "mismatch",
+            DownloadOutcome::Error => "error",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "synced" => Some(DownloadOutcome::Synced),
+            "plain" => Some(DownloadOutcome::Plain),
+            "instrumental" => Some(DownloadOutcome::Instrumental),
+            "not_found" => Some(DownloadOutcome::NotFound),
+            "mismatch" => Some(DownloadOutcome::Mismatch),
+            "error" => Some(DownloadOutcome::Error),
+            _ => None,
+        }
+    }
+}
+
+/// A download attempt to be recorded with `Database::record_download_attempt`
+#[derive(Debug, Clone)]
+pub struct DownloadAttempt {
+    pub track_id: i64,
+    pub outcome: DownloadOutcome,
+    /// DB, CACHE or API
+    pub source: Option<String>,
+    pub lrclib_id: Option<i64>,
+    pub error: Option<String>,
+    /// How well the lyrics fit the track length (0.0-1.0), when it could be checked
+    pub confidence: Option<f64>,
+}
+
+/// Latest download status of a track
+#[derive(Debug, Clone, Serialize)]
+pub struct DownloadStatus {
+    pub track_id: i64,
+    /// Unix timestamp (seconds) of the last attempt
+    pub last_attempt_at: i64,
+    pub outcome: DownloadOutcome,
+    pub source: Option<String>,
+    pub lrclib_id: Option<i64>,
+    /// Not-found results in a row since lyrics were last found
+    pub consecutive_misses: u32,
+    /// How well the last lyrics fit the track length (0.0-1.0), when it could be checked
+    pub confidence: Option<f64>,
+}
+
+/// Change-detection state stored for a scanned file
+#[derive(Debug, Clone)]
+pub struct StoredFileState {
+    pub fingerprint: Option<FileFingerprint>,
+    pub content_hash: Option<String>,
+}
+
+/// Insert a track, or update it in place so its id survives rescans
+fn upsert_track(conn: &Connection, track: &Track) -> rusqlite::Result<()> {
+    let fingerprint = track.fingerprint.as_ref();
+
+    conn.execute(
+        r#"
+        INSERT INTO tracks
+        (file_path, file_name, title, album, artist, album_artist, duration, track_number,
+         txt_lyrics, lrc_lyrics, txt_lyrics_source, lrc_lyrics_source,
+         file_mtime, file_size, sidecar_fingerprint, content_hash, instrumental, updated_at)
+        VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, CURRENT_TIMESTAMP)
+        ON CONFLICT(file_path) DO UPDATE SET
+            file_name = excluded.file_name,
+            title = excluded.title,
+            album = excluded.album,
+            artist = excluded.artist,
+            album_artist = excluded.album_artist,
+            duration = excluded.duration,
+            track_number = excluded.track_number,
+            txt_lyrics = excluded.txt_lyrics,
+            lrc_lyrics = excluded.lrc_lyrics,
+            txt_lyrics_source = excluded.txt_lyrics_source,
+            lrc_lyrics_source = excluded.lrc_lyrics_source,
+            file_mtime = excluded.file_mtime,
+            file_size = excluded.file_size,
+            sidecar_fingerprint = excluded.sidecar_fingerprint,
+            content_hash = COALESCE(excluded.content_hash, tracks.content_hash),
+            instrumental = excluded.instrumental,
+            updated_at = CURRENT_TIMESTAMP
+        "#,
+        params![
+            track.file_path,
+            track.file_name,
+            track.title,
+            track.album,
+            track.artist,
+            track.album_artist,
+            track.duration,
+            track.track_number,
+            track.txt_lyrics,
+            track.lrc_lyrics,
+            track.txt_lyrics_source.map(|s| s.as_str()),
+            track.lrc_lyrics_source.map(|s| s.as_str()),
+            fingerprint.map(|f| f.mtime),
+            fingerprint.map(|f| f.size),
+            fingerprint.map(|f| f.sidecars.as_str()),
+            track.content_hash,
+            track.instrumental,
+        ],
+    )?;
+    Ok(())
+}
+
+/// Map a `download_status` row (all columns, in table order) to a `DownloadStatus`
+fn download_status_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DownloadStatus> {
+    let outcome: String = row.get(2)?;
+    let outcome = DownloadOutcome::parse(&outcome).ok_or_else(|| {
+        rusqlite::Error::FromSqlConversionFailure(
+            2,
+            rusqlite::types::Type::Text,
+            format!("unknown download outcome '{}'", outcome).into(),
+        )
+    })?;
+
+    Ok(DownloadStatus {
+        track_id: row.get(0)?,
+        last_attempt_at: row.get(1)?,
+        outcome,
+        source: row.get(3)?,
+        lrclib_id: row.get(4)?,
+        consecutive_misses: row.get(5)?,
+        confidence: row.get(6)?,
+    })
+}
+
+/// Map a row selected with `TRACK_COLUMNS` to a `DatabaseTrack`
+fn track_from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<DatabaseTrack> {
+    let txt_lyrics_source: Option<String> = row.get(11)?;
+    let lrc_lyrics_source: Option<String> = row.get(12)?;
+
+    Ok(DatabaseTrack {
+        id: row.get(0)?,
+        file_path: row.get(1)?,
+        file_name: row.get(2)?,
+        title: row.get(3)?,
+        album_name: row.get(4)?,
+        artist_name: row.get(5)?,
+        album_artist: row.get(6)?,
+        duration: row.get(7)?,
+        track_number: row.get(8)?,
+        txt_lyrics: row.get(9)?,
+        lrc_lyrics: row.get(10)?,
+        txt_lyrics_source: txt_lyrics_source.as_deref().and_then(LyricsSource::parse),
+        lrc_lyrics_source: lrc_lyrics_source.as_deref().and_then(LyricsSource::parse),
+        instrumental: row.get(13)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A library as left by version 2, before file fingerprints existed
+    fn version_2_connection() -> Connection {
+        let conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(r#"
+            CREATE TABLE directories (id INTEGER PRIMARY KEY, path TEXT UNIQUE);
+            CREATE TABLE config (id INTEGER PRIMARY KEY, initialized BOOLEAN DEFAULT FALSE);
+            CREATE TABLE tracks (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                file_path TEXT UNIQUE, file_name TEXT, title TEXT, album TEXT, artist TEXT,
+                album_artist TEXT, duration REAL, track_number INTEGER, txt_lyrics TEXT, lrc_lyrics TEXT,
+                created_at DATETIME DEFAULT CURRENT_TIMESTAMP, updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+                txt_lyrics_source TEXT, lrc_lyrics_source TEXT
+            );
+            INSERT INTO tracks (file_path, file_name, title, album, artist, album_artist, duration)
+            VALUES ('/music/old.mp3', 'old.mp3', 'Old', 'Album', 'Artist', 'Artist', 180.0);
+            PRAGMA user_version = 2;
+        "#).unwrap();
+        conn
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_adds_fingerprint_columns() {
+        let mut conn = version_2_connection();
+        Database::upgrade_database(&mut conn, 2).unwrap();
+        let version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, CURRENT_DB_VERSION);
+
+        // Rows from before the upgrade have no fingerprint, so the next scan reads them again
+        let mut database = Database { conn };
+        let states = database.get_file_states().await.unwrap();
+        assert!(states["/music/old.mp3"].fingerprint.is_none());
+
+        let fingerprint = FileFingerprint { mtime: 1, size: 2, sidecars: "lrc=;txt=".to_string() };
+        database.update_file_fingerprint("/music/old.mp3", &fingerprint).await.unwrap();
+        let states = database.get_file_states().await.unwrap();
+        assert_eq!(states["/music/old.mp3"].fingerprint, Some(fingerprint));
+    }
+
+    fn attempt(track_id: i64, outcome: DownloadOutcome, lrclib_id: Option<i64>) -> DownloadAttempt {
+        DownloadAttempt {
+            track_id,
+            outcome,
+            source: Some("API".to_string()),
+            lrclib_id,
+            error: None,
+            confidence: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_upgrade_adds_download_status_tables() {
+        let mut conn = version_2_connection();
+        Database::upgrade_database(&mut conn, 2).unwrap();
+        let mut database = Database { conn };
+
+        database.record_download_attempt(&attempt(1, DownloadOutcome::Synced, Some(7))).await.unwrap();
+        let status = database.get_download_status(1).await.unwrap().unwrap();
+        assert_eq!((status.outcome, status.lrclib_id), (DownloadOutcome::Synced, Some(7)));
+        assert!(database.get_download_status(2).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_consecutive_misses_count_up_and_reset() {
+        let mut database = Database::new(Path::new(":memory:")).await.unwrap();
+        let misses = |database: &Database| -> u32 {
+            database.conn
+                .query_row("SELECT consecutive_misses FROM download_status WHERE track_id = 1", [], |row| row.get(0))
+                .unwrap()
+        };
+
+        database.record_download_attempt(&attempt(1, DownloadOutcome::NotFound, None)).await.unwrap();
+        assert_eq!(misses(&database), 1);
+        database.record_download_attempt(&attempt(1, DownloadOutcome::Mismatch, Some(3))).await.unwrap();
+        assert_eq!(misses(&database), 2);
+
+        // Errors are transient and leave the streak alone
+        database.record_download_attempt(&attempt(1, DownloadOutcome::Error, None)).await.unwrap();
+        assert_eq!(misses(&database), 2);
+
+        database.record_download_attempt(&attempt(1, DownloadOutcome::Plain, None)).await.unwrap();
+        let status = database.get_download_statuses().await.unwrap().remove(&1).unwrap();
+        assert_eq!((status.outcome, status.consecutive_misses), (DownloadOutcome::Plain, 0));
+        // The status describes the latest attempt only, so a stale id is not kept
+        assert_eq!(status.lrclib_id, None);
+
+        let attempts: i64 = database.conn
+            .query_row("SELECT COUNT(*) FROM download_attempts WHERE track_id = 1", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(attempts, 4);
+    }
+
+    #[tokio::test]
+    async fn test_get_download_statuses_fails_on_unknown_outcome() {
+        let mut database = Database::new(Path::new(":memory:")).await.unwrap();
+        database.record_download_attempt(&attempt(1, DownloadOutcome::Synced, None)).await.unwrap();
+        database.record_download_attempt(&attempt(2, DownloadOutcome::NotFound, None)).await.unwrap();
+        assert_eq!(database.get_download_statuses().await.unwrap().len(), 2);
+
+        database.conn.execute("UPDATE download_status SET outcome = 'bogus' WHERE track_id = 2", []).unwrap();
+        assert!(database.get_download_statuses().await.is_err());
+        assert!(database.get_download_status(2).await.is_err());
+    }
+}
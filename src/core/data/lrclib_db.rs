@@ -1,6 +1,8 @@
 use anyhow::Result;
 use rusqlite::{Connection, params};
 use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::collections::HashSet;
 use std::path::Path;
 use tracing::{debug, info};
 use fuzzy_matcher::FuzzyMatcher;
@@ -10,6 +12,8 @@ use crate::core::services::lrclib::SearchResult;
 #[derive(Debug)]
 pub struct LrclibDatabase {
     conn: Connection,
+    /// Older dumps have no flags table; searches only filter on it when it exists
+    has_flags: Cell<bool>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -26,6 +30,35 @@ pub struct LrclibTrack {
     pub updated_at: String,
 }
 
+/// A local flag against lyrics that should not be used again
+#[derive(Debug, Serialize)]
+pub struct LyricsFlag {
+    pub id: i64,
+    /// LRCLIB id of the flagged record (`tracks.id`)
+    pub lyrics_id: i64,
+    pub reason: String,
+    pub created_at: String,
+    pub track_name: Option<String>,
+    pub artist_name: Option<String>,
+    pub album_name: Option<String>,
+}
+
+const FLAGS_TABLE: &str = r#"
+    CREATE TABLE IF NOT EXISTS flags (
+      id INTEGER PRIMARY KEY AUTOINCREMENT,
+      lyrics_id INTEGER,
+      content TEXT,
+      created_at DATETIME,
+      FOREIGN KEY (lyrics_id) REFERENCES lyrics (id)
+    );
+"#;
+
+/// Filter for queries on `tracks t`: drops flagged records. `flags.lyrics_id` holds the LRCLIB
+/// id returned by searches, which is the track id, not the id of a lyrics revision.
+const NOT_FLAGGED: &str = r#"
+    t.id NOT IN (SELECT lyrics_id FROM flags WHERE lyrics_id IS NOT NULL)
+"#;
+
 #[derive(Debug, Serialize)]
 pub struct DatabaseStats {
     pub total_tracks: i64,
@@ -50,7 +83,29 @@ impl LrclibDatabase {
         // Enable shared cache to improve performance across connections
         conn.pragma_update(None, "cache", "shared")?;
 
-        Ok(LrclibDatabase { conn })
+        let has_flags = conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM sqlite_master WHERE type = 'table' AND name = 'flags')",
+            [],
+            |row| row.get::<_, bool>(0),
+        )?;
+
+        Ok(LrclibDatabase { conn, has_flags: Cell::new(has_flags) })
+    }
+
+    /// Bring an existing database up to the current schema. Only needed before writing:
+    /// reads work against any dump.
+    pub async fn migrate(&self) -> Result<()> {
+        if !self.has_flags.get() {
+            info!("Adding flags table to LRCLIB database");
+            self.conn.execute_batch(FLAGS_TABLE)?;
+            self.has_flags.set(true);
+        }
+        Ok(())
+    }
+
+    /// `NOT_FLAGGED`, or an always-true condition when there is no flags table
+    fn not_flagged(&self) -> &'static str {
+        if self.has_flags.get() { NOT_FLAGGED } else { "1" }
     }
     
     pub async fn create_schema(&self) -> Result<()> {
@@ -179,6 +234,7 @@ impl LrclibDatabase {
             "INSERT OR REPLACE INTO lrclib_metadata (key, value, updated_at) VALUES (?1, ?2, ?3)",
             params!["schema_version", "1", now],
         )?;
+        self.has_flags.set(true);
         
         Ok(())
     }
@@ -193,7 +249,7 @@ impl LrclibDatabase {
         debug!("Searching local LRCLIB database for exact match: {} - {} ({})", artist, title, album);
         
         // Try exact match first using pre-computed lowercase columns for better performance
-        let mut stmt = self.conn.prepare(r#"
+        let mut stmt = self.conn.prepare(&format!(r#"
             SELECT
                 t.id, t.name, t.artist_name, t.album_name, t.duration,
                 l.plain_lyrics, l.synced_lyrics, l.instrumental
@@ -203,9 +259,10 @@ impl LrclibDatabase {
               AND t.artist_name_lower = LOWER(?2)
               AND t.album_name_lower = LOWER(?3)
               AND ABS(t.duration - ?4) <= 5.0
+              AND {}
             ORDER BY ABS(t.duration - ?4)
             LIMIT 1
-        "#)?;
+        "#, self.not_flagged()))?;
         
         let result = stmt.query_row(params![title, artist, album, duration], |row| {
             Ok(SearchResult {
@@ -274,6 +331,7 @@ impl LrclibDatabase {
             return Ok(Vec::new());
         }
         
+        conditions.push(self.not_flagged());
         let where_clause = conditions.join(" AND ");
         let title_lower = title.to_lowercase().replace("'", "''");
         let artist_lower = artist.to_lowercase().replace("'", "''");
@@ -386,7 +444,7 @@ impl LrclibDatabase {
         let matcher = SkimMatcherV2::default();
         
         // Get all tracks from the database
-        let mut stmt = self.conn.prepare(r#"
+        let mut stmt = self.conn.prepare(&format!(r#"
             SELECT 
                 t.id,
                 t.name,
@@ -398,12 +456,13 @@ impl LrclibDatabase {
                 l.instrumental
             FROM tracks t
             LEFT JOIN lyrics l ON t.last_lyrics_id = l.id
-            WHERE (t.name IS NOT NULL AND t.name != '') 
+            WHERE ((t.name IS NOT NULL AND t.name != '') 
                OR (t.artist_name IS NOT NULL AND t.artist_name != '')
-               OR (t.album_name IS NOT NULL AND t.album_name != '')
+               OR (t.album_name IS NOT NULL AND t.album_name != ''))
+              AND {}
             ORDER BY t.id
             LIMIT 10000
-        "#)?;
+        "#, self.not_flagged()))?;
         
         let rows = stmt.query_map([], |row| {
             Ok((
@@ -488,6 +547,58 @@ impl LrclibDatabase {
         Ok(results)
     }
     
+    /// LRCLIB ids of all flagged records
+    pub async fn flagged_lyrics_ids(&self) -> Result<HashSet<i64>> {
+        if !self.has_flags.get() {
+            return Ok(HashSet::new());
+        }
+
+        let mut stmt = self.conn.prepare("SELECT DISTINCT lyrics_id FROM flags WHERE lyrics_id IS NOT NULL")?;
+        let ids = stmt.query_map([], |row| row.get::<_, i64>(0))?
+            .collect::<Result<HashSet<i64>, _>>()?;
+
+        Ok(ids)
+    }
+
+    pub async fn add_flag(&self, lyrics_id: i64, reason: &str) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO flags (lyrics_id, content, created_at) VALUES (?1, ?2, ?3)",
+            params![lyrics_id, reason, chrono::Utc::now().to_rfc3339()],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Remove a flag; returns whether it existed
+    pub async fn remove_flag(&self, flag_id: i64) -> Result<bool> {
+        let removed = self.conn.execute("DELETE FROM flags WHERE id = ?1", params![flag_id])?;
+        Ok(removed > 0)
+    }
+
+    pub async fn list_flags(&self) -> Result<Vec<LyricsFlag>> {
+        let mut stmt = self.conn.prepare(r#"
+            SELECT f.id, f.lyrics_id, f.content, f.created_at, t.name, t.artist_name, t.album_name
+            FROM flags f
+            LEFT JOIN tracks t ON t.id = f.lyrics_id
+            WHERE f.lyrics_id IS NOT NULL
+            ORDER BY f.id
+        "#)?;
+
+        let flags = stmt.query_map([], |row| {
+            Ok(LyricsFlag {
+                id: row.get(0)?,
+                lyrics_id: row.get(1)?,
+                reason: row.get::<_, Option<String>>(2)?.unwrap_or_default(),
+                created_at: row.get::<_, Option<String>>(3)?.unwrap_or_default(),
+                track_name: row.get(4)?,
+                artist_name: row.get(5)?,
+                album_name: row.get(6)?,
+            })
+        })?
+        .collect::<Result<Vec<LyricsFlag>, _>>()?;
+
+        Ok(flags)
+    }
+
     pub async fn get_statistics(&self) -> Result<DatabaseStats> {
         let mut stmt = self.conn.prepare(r#"
             SELECT 
@@ -542,4 +653,80 @@ impl LrclibDatabase {
         self.conn.execute_batch(sql)?;
         Ok(())
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    async fn database_with_tracks() -> LrclibDatabase {
+        let db = LrclibDatabase::new(Path::new(":memory:")).await.unwrap();
+        db.create_schema().await.unwrap();
+
+        // Same lyrics on two records, so only ids tell them apart
+        for (name, album) in [("Song", "First"), ("Song", "Second")] {
+            db.insert_track(&LrclibTrack {
+                id: 0,
+                name: Some(name.to_string()),
+                artist_name: Some("Artist".to_string()),
+                album_name: Some(album.to_string()),
+                duration: Some(200.0),
+                plain_lyrics: Some("Words".to_string()),
+                synced_lyrics: Some("[00:01.00]Words".to_string()),
+                instrumental: false,
+                created_at: "2026-01-01T00:00:00Z".to_string(),
+                updated_at: "2026-01-01T00:00:00Z".to_string(),
+            }).await.unwrap();
+        }
+
+        db
+    }
+
+    #[tokio::test]
+    async fn test_add_list_and_remove_flags() {
+        let db = database_with_tracks().await;
+
+        let flag_id = db.add_flag(1, "wrong song").await.unwrap();
+        let flags = db.list_flags().await.unwrap();
+        assert_eq!(flags.len(), 1);
+        assert_eq!((flags[0].id, flags[0].lyrics_id, flags[0].reason.as_str()), (flag_id, 1, "wrong song"));
+        assert_eq!(flags[0].album_name.as_deref(), Some("First"));
+        assert_eq!(db.flagged_lyrics_ids().await.unwrap(), HashSet::from([1]));
+
+        assert!(db.remove_flag(flag_id).await.unwrap());
+        assert!(!db.remove_flag(flag_id).await.unwrap());
+        assert!(db.list_flags().await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_flagged_lyrics_excluded_by_id() {
+        let db = database_with_tracks().await;
+        db.add_flag(1, "wrong song").await.unwrap();
+
+        assert!(db.search_exact("Song", "Artist", "First", 200.0).await.unwrap().is_none());
+        let other = db.search_exact("Song", "Artist", "Second", 200.0).await.unwrap().unwrap();
+        assert_eq!(other.album_name.as_deref(), Some("Second"));
+
+        let fuzzy = db.fuzzy_search("Song", "Artist", "", "", None).await.unwrap();
+        let albums: Vec<_> = fuzzy.iter().filter_map(|result| result.album_name.as_deref()).collect();
+        assert_eq!(albums, vec!["Second"]);
+    }
+
+    #[tokio::test]
+    async fn test_flags_match_track_ids_not_lyrics_ids() {
+        let db = database_with_tracks().await;
+        // A newer revision for the second record: track 2 now points at lyrics 3
+        db.execute_batch(
+            "INSERT INTO lyrics (plain_lyrics, track_id, has_plain_lyrics, has_synced_lyrics, instrumental, source)
+             VALUES ('Better words', 2, 1, 0, 0, 'lrclib')",
+        ).await.unwrap();
+        db.add_flag(2, "wrong song").await.unwrap();
+
+        assert!(db.search_exact("Song", "Artist", "Second", 200.0).await.unwrap().is_none());
+        let first = db.search_exact("Song", "Artist", "First", 200.0).await.unwrap().unwrap();
+        assert_eq!(first.id, Some(1));
+
+        let flags = db.list_flags().await.unwrap();
+        assert_eq!(flags[0].album_name.as_deref(), Some("Second"));
+    }
 }
\ No newline at end of file
@@ -0,0 +1,156 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+use crate::core::files::lyrics::parse_timed_lines;
+
+/// What to do with lyrics whose timing does not fit the audio file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DurationMismatchAction {
+    /// Save the lyrics but log the mismatch and record a low confidence
+    #[default]
+    Flag,
+    /// Do not save the lyrics
+    Reject,
+}
+
+impl DurationMismatchAction {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            DurationMismatchAction::Flag => "flag",
+            DurationMismatchAction::Reject => "reject",
+        }
+    }
+}
+
+impl fmt::Display for DurationMismatchAction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for DurationMismatchAction {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "flag" => Ok(DurationMismatchAction::Flag),
+            "reject" => Ok(DurationMismatchAction::Reject),
+            other => Err(format!(
+                "Invalid duration mismatch action '{}'. Use: flag, reject",
+                other
+            )),
+        }
+    }
+}
+
+/// Threshold and action applied when lyrics are saved
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationPolicy {
+    pub threshold_seconds: f64,
+    pub action: DurationMismatchAction,
+}
+
+impl Default for DurationPolicy {
+    fn default() -> Self {
+        Self {
+            threshold_seconds: 10.0,
+            action: DurationMismatchAction::Flag,
+        }
+    }
+}
+
+/// How well a lyrics result fits the length of the audio file
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DurationCheck {
+    /// From 0.0 (clearly another version of the song) to 1.0 (lengths agree)
+    pub confidence: f64,
+    /// Difference between the result's duration and the track's, in seconds
+    pub duration_delta: Option<f64>,
+    /// How far the last LRC timestamp runs past the end of the track, in seconds
+    pub lrc_overrun: Option<f64>,
+    /// Either difference is above the threshold
+    pub mismatch: bool,
+}
+
+impl DurationCheck {
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(delta) = self.duration_delta {
+            parts.push(format!("result length differs by {:.1}s", delta));
+        }
+        if let Some(overrun) = self.lrc_overrun.filter(|o| *o > 0.0) {
+            parts.push(format!("last line is {:.1}s past the end", overrun));
+        }
+        parts.push(format!("confidence {:.2}", self.confidence));
+        parts.join(", ")
+    }
+}
+
+/// Compare a lyrics result with the track length. Returns `None` when there is nothing to
+/// compare: the track length is unknown, or the result has neither a duration nor synced lyrics.
+///
+/// Each difference scores `1 - difference / (2 * threshold)`, so a difference right at the
+/// threshold gives 0.5; the confidence is the lower of the two scores.
+pub fn check_duration(
+    track_duration: f64,
+    result_duration: Option<f64>,
+    synced_lyrics: Option<&str>,
+    threshold_seconds: f64,
+) -> Option<DurationCheck> {
+    if track_duration <= 0.0 {
+        return None;
+    }
+
+    let duration_delta = result_duration
+        .filter(|d| *d > 0.0)
+        .map(|d| (d - track_duration).abs());
+    let lrc_overrun = synced_lyrics
+        .and_then(|lyrics| parse_timed_lines(lyrics).last().map(|(millis, _)| *millis))
+        .map(|millis| (millis as f64 / 1000.0 - track_duration).max(0.0));
+
+    if duration_delta.is_none() && lrc_overrun.is_none() {
+        return None;
+    }
+
+    let score = |difference: f64| (1.0 - difference / (2.0 * threshold_seconds)).clamp(0.0, 1.0);
+    let confidence = duration_delta
+        .into_iter()
+        .chain(lrc_overrun)
+        .map(score)
+        .fold(1.0, f64::min);
+    let mismatch = duration_delta.into_iter().chain(lrc_overrun).any(|d| d > threshold_seconds);
+
+    Some(DurationCheck {
+        confidence,
+        duration_delta,
+        lrc_overrun,
+        mismatch,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_duration_scores_differences() {
+        assert_eq!(check_duration(0.0, Some(200.0), None, 10.0), None);
+        assert_eq!(check_duration(200.0, None, None, 10.0), None);
+
+        let check = check_duration(200.0, Some(201.0), Some("[00:10.00]a\n[03:15.00]b"), 10.0).unwrap();
+        assert!(!check.mismatch);
+        assert!((check.confidence - 0.95).abs() < 1e-9);
+
+        // Album-version lyrics on a radio edit: the last line is 30s past the end
+        let check = check_duration(200.0, None, Some("[00:10.00]a\n[03:50.00]b"), 10.0).unwrap();
+        assert!(check.mismatch);
+        assert_eq!(check.lrc_overrun, Some(30.0));
+        assert_eq!(check.confidence, 0.0);
+
+        let check = check_duration(200.0, Some(212.0), None, 10.0).unwrap();
+        assert!(check.mismatch);
+        assert!((check.confidence - 0.4).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,393 @@
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+use crate::core::files::lyrics::{format_lrc_timestamp, is_instrumental_marker};
+
+/// Whether an issue makes the file unusable or only worth a look
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LintSeverity {
+    Error,
+    Warning,
+}
+
+/// Kind of problem found in an `.lrc` file
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum LintIssueKind {
+    /// No content, or no timestamped lines at all
+    Empty,
+    /// Byte order mark, UTF-16 or non-UTF-8 bytes
+    Encoding,
+    /// Time tag that the LRC parser would not read as intended
+    MalformedTimestamp,
+    /// Line timed earlier than the line before it
+    OutOfOrder,
+    /// Timestamp past the end of the track
+    BeyondDuration,
+}
+
+impl LintIssueKind {
+    pub fn severity(self) -> LintSeverity {
+        match self {
+            LintIssueKind::Empty | LintIssueKind::MalformedTimestamp => LintSeverity::Error,
+            LintIssueKind::Encoding | LintIssueKind::OutOfOrder | LintIssueKind::BeyondDuration => {
+                LintSeverity::Warning
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct LintIssue {
+    pub kind: LintIssueKind,
+    /// 1-based line number, when the issue is tied to a line
+    pub line: Option<usize>,
+    pub message: String,
+    pub fixable: bool,
+}
+
+/// Result of linting one `.lrc` file
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct LrcLint {
+    pub issues: Vec<LintIssue>,
+    /// Rewritten content with every fixable issue resolved, if anything changed
+    #[serde(skip)]
+    pub fixed: Option<String>,
+}
+
+impl LrcLint {
+    pub fn is_clean(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    fn push(&mut self, kind: LintIssueKind, line: Option<usize>, message: String, fixable: bool) {
+        self.issues.push(LintIssue { kind, line, message, fixable });
+    }
+}
+
+/// A line split into its leading tags and the lyrics text after them
+struct ParsedLine<'a> {
+    number: usize,
+    raw: &'a str,
+    /// Leading `[label: value]` tags, kept verbatim
+    id_tags: Vec<&'a str>,
+    times: Vec<u64>,
+    text: &'a str,
+    /// A time tag needed rewriting
+    needs_fix: bool,
+    /// A time tag could not be understood at all
+    unfixable: bool,
+}
+
+/// Lint the `.lrc` file at `path`. `duration` is the track length in seconds, if known.
+pub fn lint_lrc_file(path: &Path, duration: Option<f64>) -> Result<LrcLint> {
+    let bytes = std::fs::read(path)?;
+    Ok(lint_lrc(&bytes, duration))
+}
+
+/// Lint raw `.lrc` bytes. `duration` is the track length in seconds, if known.
+pub fn lint_lrc(bytes: &[u8], duration: Option<f64>) -> LrcLint {
+    let mut lint = LrcLint::default();
+
+    let Some((text, reencoded)) = decode(bytes, &mut lint) else {
+        return lint;
+    };
+
+    if text.contains('\u{FFFD}') {
+        lint.push(LintIssueKind::Encoding, None, "Contains replacement characters (lost during an earlier conversion)".to_string(), false);
+    }
+
+    if text.trim().is_empty() {
+        lint.push(LintIssueKind::Empty, None, "File is empty".to_string(), false);
+        return lint;
+    }
+
+    if is_instrumental_marker(&text) {
+        if reencoded {
+            lint.fixed = Some(text);
+        }
+        return lint;
+    }
+
+    let lines: Vec<ParsedLine> = text.lines().enumerate().map(|(i, raw)| parse_line(i + 1, raw, &mut lint)).collect();
+    let any_unfixable = lines.iter().any(|line| line.unfixable);
+
+    let timed: Vec<&ParsedLine> = lines.iter().filter(|line| !line.times.is_empty()).collect();
+    if timed.is_empty() && !any_unfixable {
+        lint.push(LintIssueKind::Empty, None, "No timestamped lines".to_string(), false);
+        return lint;
+    }
+
+    let mut out_of_order = false;
+    for pair in timed.windows(2) {
+        let (previous, current) = (pair[0].times[0], pair[1].times[0]);
+        if current < previous {
+            out_of_order = true;
+            lint.push(
+                LintIssueKind::OutOfOrder,
+                Some(pair[1].number),
+                format!("{} comes after {}", format_lrc_timestamp(clamp_ms(current)), format_lrc_timestamp(clamp_ms(previous))),
+                !any_unfixable,
+            );
+        }
+    }
+
+    if let Some(duration) = duration.filter(|d| *d > 0.0) {
+        let duration_ms = (duration * 1000.0) as u64;
+        let last = timed.iter().flat_map(|line| line.times.iter().map(move |t| (*t, line.number))).max();
+        if let Some((last_ms, number)) = last.filter(|(ms, _)| *ms > duration_ms) {
+            lint.push(
+                LintIssueKind::BeyondDuration,
+                Some(number),
+                format!(
+                    "Last timestamp {} is beyond the track length {}",
+                    format_lrc_timestamp(clamp_ms(last_ms)),
+                    format_lrc_timestamp(clamp_ms(duration_ms)),
+                ),
+                false,
+            );
+        }
+    }
+
+    let needs_rewrite = reencoded || (out_of_order && !any_unfixable) || lines.iter().any(|line| line.needs_fix);
+    if needs_rewrite {
+        let mut fixed = if out_of_order && !any_unfixable {
+            rebuild_sorted(&lines)
+        } else {
+            rebuild_in_place(&lines)
+        };
+        if text.ends_with('\n') {
+            fixed.push('\n');
+        }
+        lint.fixed = Some(fixed);
+    }
+
+    lint
+}
+
+/// Decode the file as UTF-8, recording encoding issues. The flag is set when the
+/// text had to be converted, so a fix would rewrite the file as plain UTF-8.
+fn decode(bytes: &[u8], lint: &mut LrcLint) -> Option<(String, bool)> {
+    if let Some(rest) = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]) {
+        lint.push(LintIssueKind::Encoding, None, "Starts with a UTF-8 byte order mark".to_string(), true);
+        let text = match std::str::from_utf8(rest) {
+            Ok(text) => text.to_string(),
+            Err(_) => decode_latin1(rest, lint),
+        };
+        return Some((text, true));
+    }
+
+    let utf16_le = bytes.starts_with(&[0xFF, 0xFE]);
+    if utf16_le || bytes.starts_with(&[0xFE, 0xFF]) {
+        let units: Vec<u16> = bytes[2..]
+            .chunks_exact(2)
+            .map(|pair| if utf16_le { u16::from_le_bytes([pair[0], pair[1]]) } else { u16::from_be_bytes([pair[0], pair[1]]) })
+            .collect();
+        return match String::from_utf16(&units) {
+            Ok(text) => {
+                lint.push(LintIssueKind::Encoding, None, "Encoded as UTF-16 instead of UTF-8".to_string(), true);
+                Some((text, true))
+            }
+            Err(_) => {
+                lint.push(LintIssueKind::Encoding, None, "Invalid UTF-16 content".to_string(), false);
+                None
+            }
+        };
+    }
+
+    match std::str::from_utf8(bytes) {
+        Ok(text) => Some((text.to_string(), false)),
+        Err(_) => Some((decode_latin1(bytes, lint), true)),
+    }
+}
+
+/// Legacy 8-bit files are almost always Latin-1/Windows-1252; map bytes straight to code points
+fn decode_latin1(bytes: &[u8], lint: &mut LrcLint) -> String {
+    let valid_up_to = std::str::from_utf8(bytes).err().map(|e| e.valid_up_to()).unwrap_or(0);
+    let line = bytes[..valid_up_to].iter().filter(|b| **b == b'\n').count() + 1;
+    lint.push(
+        LintIssueKind::Encoding,
+        Some(line),
+        "Not valid UTF-8; decoded as Latin-1".to_string(),
+        true,
+    );
+    bytes.iter().map(|&b| b as char).collect()
+}
+
+fn parse_line<'a>(number: usize, raw: &'a str, lint: &mut LrcLint) -> ParsedLine<'a> {
+    let mut line = ParsedLine {
+        number,
+        raw,
+        id_tags: Vec::new(),
+        times: Vec::new(),
+        text: "",
+        needs_fix: false,
+        unfixable: false,
+    };
+
+    let mut rest = raw.trim_start();
+    while rest.starts_with('[') {
+        let Some(end) = rest.find(']') else {
+            if rest[1..].starts_with(|c: char| c.is_ascii_digit()) {
+                lint.push(LintIssueKind::MalformedTimestamp, Some(number), format!("Unclosed time tag in \"{}\"", raw.trim()), false);
+                line.unfixable = true;
+            }
+            break;
+        };
+        let tag = &rest[..=end];
+        let content = &rest[1..end];
+
+        if content.starts_with(|c: char| c.is_ascii_digit()) {
+            match parse_time_tag(content) {
+                Some((millis, true)) => line.times.push(millis),
+                Some((millis, false)) => {
+                    let canonical = format_lrc_timestamp(clamp_ms(millis));
+                    lint.push(LintIssueKind::MalformedTimestamp, Some(number), format!("{} should be {}", tag, canonical), true);
+                    line.times.push(millis);
+                    line.needs_fix = true;
+                }
+                None => {
+                    lint.push(LintIssueKind::MalformedTimestamp, Some(number), format!("Unreadable time tag {}", tag), false);
+                    line.unfixable = true;
+                }
+            }
+        } else {
+            line.id_tags.push(tag);
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    line.text = rest;
+    line
+}
+
+/// Parse the inside of a time tag into milliseconds, leniently. The flag is set when
+/// the tag is already in the `mm:ss.xx` form the LRC parser reads unambiguously.
+pub(crate) fn parse_time_tag(content: &str) -> Option<(u64, bool)> {
+    let (minutes, rest) = content.split_once(':')?;
+    let (seconds, fraction) = match rest.find(['.', ':']) {
+        Some(i) => (&rest[..i], Some((&rest[i..i + 1], &rest[i + 1..]))),
+        None => (rest, None),
+    };
+
+    let all_digits = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_digit());
+    if !all_digits(minutes) || minutes.len() > 4 || !all_digits(seconds) || seconds.len() > 2 {
+        return None;
+    }
+    if let Some((_, digits)) = fraction {
+        if !all_digits(digits) || digits.len() > 3 {
+            return None;
+        }
+    }
+
+    let minutes: u64 = minutes.parse().ok()?;
+    let second_value: u64 = seconds.parse().ok()?;
+    let fraction_ms = match fraction {
+        Some((_, digits)) => digits.parse::<u64>().ok()? * 10u64.pow(3 - digits.len() as u32),
+        None => 0,
+    };
+
+    let canonical = seconds.len() == 2
+        && second_value < 60
+        && fraction.is_none_or(|(separator, digits)| separator == "." && digits.len() == 2);
+
+    Some((minutes * 60_000 + second_value * 1000 + fraction_ms, canonical))
+}
+
+fn clamp_ms(millis: u64) -> u32 {
+    millis.min(u32::MAX as u64) as u32
+}
+
+fn time_tags(times: &[u64]) -> String {
+    times.iter().map(|t| format_lrc_timestamp(clamp_ms(*t))).collect()
+}
+
+/// Rewrite only the lines whose time tags were not canonical
+fn rebuild_in_place(lines: &[ParsedLine]) -> String {
+    lines
+        .iter()
+        .map(|line| {
+            if line.needs_fix && !line.unfixable {
+                format!("{}{}{}", line.id_tags.concat(), time_tags(&line.times), line.text)
+            } else {
+                line.raw.to_string()
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Metadata lines first, then one line per time tag in time order
+fn rebuild_sorted(lines: &[ParsedLine]) -> String {
+    let mut header: Vec<String> = Vec::new();
+    let mut timed: Vec<(u64, String)> = Vec::new();
+
+    for line in lines {
+        if line.times.is_empty() {
+            if !line.raw.trim().is_empty() {
+                header.push(line.raw.to_string());
+            }
+            continue;
+        }
+        for &time in &line.times {
+            timed.push((time, format!("{}{}{}", line.id_tags.concat(), format_lrc_timestamp(clamp_ms(time)), line.text)));
+        }
+    }
+
+    // Stable sort keeps lines sharing a timestamp in file order
+    timed.sort_by_key(|(time, _)| *time);
+    header.into_iter().chain(timed.into_iter().map(|(_, line)| line)).collect::<Vec<_>>().join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(lint: &LrcLint) -> Vec<LintIssueKind> {
+        lint.issues.iter().map(|issue| issue.kind).collect()
+    }
+
+    #[test]
+    fn test_clean_file_has_no_issues() {
+        let lint = lint_lrc(b"[ar: Artist]\n[00:01.00]One\n[00:02.50]Two\n", Some(10.0));
+        assert!(lint.is_clean());
+        assert!(lint.fixed.is_none());
+    }
+
+    #[test]
+    fn test_fixes_timestamps_and_order() {
+        let lint = lint_lrc(b"[ti: Song]\n[00:05.00]Two\n[00:01.234]One\n[0:9:5]Three\n", None);
+        assert_eq!(
+            kinds(&lint),
+            vec![LintIssueKind::MalformedTimestamp, LintIssueKind::MalformedTimestamp, LintIssueKind::OutOfOrder]
+        );
+        assert_eq!(
+            lint.fixed.as_deref(),
+            Some("[ti: Song]\n[00:01.23]One\n[00:05.00]Two\n[00:09.50]Three\n")
+        );
+    }
+
+    #[test]
+    fn test_reports_unfixable_problems() {
+        assert_eq!(kinds(&lint_lrc(b"  \n", None)), vec![LintIssueKind::Empty]);
+
+        let lint = lint_lrc(b"[00:01.00]One\n[00:x1]Broken\n[03:10.00]Late\n", Some(180.0));
+        assert_eq!(kinds(&lint), vec![LintIssueKind::MalformedTimestamp, LintIssueKind::BeyondDuration]);
+        assert!(lint.issues.iter().all(|issue| !issue.fixable));
+        assert!(lint.fixed.is_none());
+    }
+
+    #[test]
+    fn test_converts_latin1_and_bom() {
+        let lint = lint_lrc(b"\xEF\xBB\xBF[00:01.00]Caf\xC3\xA9\n", None);
+        assert_eq!(kinds(&lint), vec![LintIssueKind::Encoding]);
+        assert_eq!(lint.fixed.as_deref(), Some("[00:01.00]Café\n"));
+
+        let lint = lint_lrc(b"[00:01.00]Caf\xE9\n", None);
+        assert_eq!(kinds(&lint), vec![LintIssueKind::Encoding]);
+        assert_eq!(lint.fixed.as_deref(), Some("[00:01.00]Café\n"));
+    }
+}
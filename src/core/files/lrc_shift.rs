@@ -0,0 +1,186 @@
+use crate::core::files::lrc_lint::parse_time_tag;
+use crate::core::files::lyrics::format_lrc_timestamp;
+
+/// What to do with the `[offset:]` tag while shifting
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum OffsetTagMode {
+    /// Rewrite timestamps and leave any `[offset:]` tag as it is
+    #[default]
+    Keep,
+    /// Fold an existing `[offset:]` tag into the timestamps and drop it
+    Honor,
+    /// Leave timestamps alone and record the shift in the `[offset:]` tag
+    Write,
+}
+
+/// Result of shifting LRC text
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShiftedLrc {
+    pub text: String,
+    /// Total shift applied to the timestamps, in milliseconds
+    pub applied_ms: i64,
+    /// Time tags that would have gone below zero and were set to `[00:00.00]`
+    pub clamped: usize,
+}
+
+/// Parse an offset like `+350ms`, `-1.5s` or `200` (milliseconds) into milliseconds
+pub fn parse_offset(spec: &str) -> Result<i64, String> {
+    let spec = spec.trim();
+    let invalid = || format!("Invalid offset '{}'. Use e.g. +350ms, -1.5s or 200", spec);
+
+    let (number, scale) = if let Some(number) = spec.strip_suffix("ms") {
+        (number, 1.0)
+    } else if let Some(number) = spec.strip_suffix('s') {
+        (number, 1000.0)
+    } else {
+        (spec, 1.0)
+    };
+
+    let value: f64 = number.trim().parse().map_err(|_| invalid())?;
+    if !value.is_finite() {
+        return Err(invalid());
+    }
+    Ok((value * scale).round() as i64)
+}
+
+/// Shift every time tag in `text` by `offset_ms`; positive values make lines appear later.
+///
+/// Lines, metadata and unreadable tags are kept as they are; only time tags are rewritten.
+pub fn shift_lrc(text: &str, offset_ms: i64, mode: OffsetTagMode) -> ShiftedLrc {
+    // In LRC a positive [offset:] makes lyrics appear sooner, the opposite of our shift
+    let existing_tag = find_offset_tag(text);
+
+    if mode == OffsetTagMode::Write {
+        let value = existing_tag.unwrap_or(0) - offset_ms;
+        return ShiftedLrc {
+            text: write_offset_tag(text, value),
+            applied_ms: 0,
+            clamped: 0,
+        };
+    }
+
+    let applied_ms = match mode {
+        OffsetTagMode::Honor => offset_ms - existing_tag.unwrap_or(0),
+        _ => offset_ms,
+    };
+
+    let mut clamped = 0;
+    let lines: Vec<String> = text
+        .lines()
+        .filter(|line| !(mode == OffsetTagMode::Honor && is_offset_line(line)))
+        .map(|line| shift_line(line, applied_ms, &mut clamped))
+        .collect();
+
+    let mut shifted = lines.join("\n");
+    if text.ends_with('\n') {
+        shifted.push('\n');
+    }
+
+    ShiftedLrc {
+        text: shifted,
+        applied_ms,
+        clamped,
+    }
+}
+
+fn shift_line(line: &str, offset_ms: i64, clamped: &mut usize) -> String {
+    let indent = &line[..line.len() - line.trim_start().len()];
+    let mut rest = line.trim_start();
+    let mut out = String::from(indent);
+
+    while rest.starts_with('[') {
+        let Some(end) = rest.find(']') else { break };
+        let content = &rest[1..end];
+
+        match parse_time_tag(content) {
+            Some((millis, _)) => {
+                let shifted = millis as i64 + offset_ms;
+                if shifted < 0 {
+                    *clamped += 1;
+                }
+                out.push_str(&format_lrc_timestamp(shifted.clamp(0, u32::MAX as i64) as u32));
+            }
+            None => out.push_str(&rest[..=end]),
+        }
+
+        rest = &rest[end + 1..];
+    }
+
+    out.push_str(rest);
+    out
+}
+
+/// Split a `[offset:...]` line into its value, if it is one
+fn offset_tag_value(line: &str) -> Option<&str> {
+    let inner = line.trim().strip_prefix('[')?.strip_suffix(']')?;
+    let (label, value) = inner.split_once(':')?;
+    label.trim().eq_ignore_ascii_case("offset").then(|| value.trim())
+}
+
+fn is_offset_line(line: &str) -> bool {
+    offset_tag_value(line).is_some()
+}
+
+fn find_offset_tag(text: &str) -> Option<i64> {
+    text.lines()
+        .filter_map(offset_tag_value)
+        .find_map(|value| value.trim_start_matches('+').parse::<i64>().ok())
+}
+
+/// Replace the `[offset:]` tag, or add one after the leading metadata lines
+fn write_offset_tag(text: &str, value: i64) -> String {
+    let tag = format!("[offset:{:+}]", value);
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    match lines.iter().position(|line| is_offset_line(line)) {
+        Some(index) => lines[index] = &tag,
+        None => {
+            let header_end = lines
+                .iter()
+                .take_while(|line| {
+                    let line = line.trim_start();
+                    line.starts_with('[') && !line[1..].starts_with(|c: char| c.is_ascii_digit())
+                })
+                .count();
+            lines.insert(header_end, &tag);
+        }
+    }
+
+    let mut out = lines.join("\n");
+    if text.ends_with('\n') || text.is_empty() {
+        out.push('\n');
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_offset() {
+        assert_eq!(parse_offset("+350ms"), Ok(350));
+        assert_eq!(parse_offset("-1.5s"), Ok(-1500));
+        assert_eq!(parse_offset("200"), Ok(200));
+        assert!(parse_offset("later").is_err());
+    }
+
+    #[test]
+    fn test_shift_lrc_modes() {
+        let lrc = "[ar: Artist]\n[offset:+100]\n[00:00.20]One\n[00:01.00][00:05.50]Two\n";
+
+        let shifted = shift_lrc(lrc, -300, OffsetTagMode::Keep);
+        assert_eq!(shifted.text, "[ar: Artist]\n[offset:+100]\n[00:00.00]One\n[00:00.70][00:05.20]Two\n");
+        assert_eq!(shifted.clamped, 1);
+
+        let shifted = shift_lrc(lrc, 350, OffsetTagMode::Honor);
+        assert_eq!(shifted.applied_ms, 250);
+        assert_eq!(shifted.text, "[ar: Artist]\n[00:00.45]One\n[00:01.25][00:05.75]Two\n");
+
+        let shifted = shift_lrc(lrc, 350, OffsetTagMode::Write);
+        assert_eq!(shifted.text, "[ar: Artist]\n[offset:-250]\n[00:00.20]One\n[00:01.00][00:05.50]Two\n");
+
+        let shifted = shift_lrc("[ti: Song]\n[00:01.00]One", 350, OffsetTagMode::Write);
+        assert_eq!(shifted.text, "[ti: Song]\n[offset:-350]\n[00:01.00]One");
+    }
+}
@@ -1,9 +1,144 @@
 use anyhow::Result;
-use std::fs::{remove_file, write};
+use lofty::config::{ParseOptions, WriteOptions};
+use lofty::file::{AudioFile, TaggedFile};
+use lofty::flac::FlacFile;
+use lofty::id3::v2::{
+    BinaryFrame, Frame, FrameId, Id3v2Tag, SyncTextContentType, SynchronizedTextFrame,
+    TimestampFormat, UnsynchronizedTextFrame,
+};
+use lofty::mp4::{Atom, AtomData, AtomIdent, Ilst, Mp4File};
+use lofty::mpeg::MpegFile;
+use lofty::ogg::{OpusFile, VorbisComments, VorbisFile};
+use lofty::TextEncoding;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::str::FromStr;
+use std::fs::{remove_file, write, File};
 use std::path::{Path, PathBuf};
 use tracing::{debug, warn};
 
 use crate::core::data::database::DatabaseTrack;
+use crate::error::LyricsError;
+
+/// Where lyrics are written for a given audio format
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LyricsStorage {
+    /// `.lrc`/`.txt` file next to the track
+    Sidecar,
+    /// Tags inside the audio file only
+    Embed,
+    /// Sidecar file and embedded tags
+    Both,
+}
+
+impl LyricsStorage {
+    pub fn writes_sidecar(self) -> bool {
+        matches!(self, LyricsStorage::Sidecar | LyricsStorage::Both)
+    }
+
+    pub fn embeds(self) -> bool {
+        matches!(self, LyricsStorage::Embed | LyricsStorage::Both)
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LyricsStorage::Sidecar => "sidecar",
+            LyricsStorage::Embed => "embed",
+            LyricsStorage::Both => "both",
+        }
+    }
+}
+
+impl fmt::Display for LyricsStorage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for LyricsStorage {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "sidecar" => Ok(LyricsStorage::Sidecar),
+            "embed" => Ok(LyricsStorage::Embed),
+            "both" => Ok(LyricsStorage::Both),
+            other => Err(format!(
+                "Invalid lyrics storage '{}'. Use: sidecar, embed, both",
+                other
+            )),
+        }
+    }
+}
+
+/// Contents of the .lrc sidecar written for tracks LRCLIB marks as instrumental
+pub const INSTRUMENTAL_MARKER: &str = "[au: instrumental]";
+
+/// Audio formats that `LyricsManager::embed_lyrics` can write tags into
+pub const EMBEDDABLE_FORMATS: [&str; 7] = ["mp3", "flac", "m4a", "mp4", "alac", "ogg", "opus"];
+
+/// Per-format storage decision for downloaded lyrics
+#[derive(Debug, Clone, Default)]
+pub struct LyricsStoragePolicy {
+    embedding_enabled: bool,
+    overrides: BTreeMap<String, LyricsStorage>,
+}
+
+impl LyricsStoragePolicy {
+    /// Sidecar files only, for every format
+    pub fn sidecar_only() -> Self {
+        Self::default()
+    }
+
+    /// `overrides` maps lowercase extensions to a storage mode; formats without an
+    /// override embed and keep the sidecar when embedding is enabled.
+    pub fn new(embedding_enabled: bool, overrides: BTreeMap<String, LyricsStorage>) -> Self {
+        Self {
+            embedding_enabled,
+            overrides,
+        }
+    }
+
+    pub fn for_path(&self, track_path: &str) -> LyricsStorage {
+        let Some(ext) = file_extension(track_path) else {
+            return LyricsStorage::Sidecar;
+        };
+
+        if !self.embedding_enabled || !EMBEDDABLE_FORMATS.contains(&ext.as_str()) {
+            return LyricsStorage::Sidecar;
+        }
+
+        self.overrides.get(&ext).copied().unwrap_or(LyricsStorage::Both)
+    }
+}
+
+/// Parse a `format=mode` list such as `m4a=embed,opus=both`
+pub fn parse_storage_overrides(spec: &str) -> std::result::Result<BTreeMap<String, LyricsStorage>, String> {
+    let mut overrides = BTreeMap::new();
+
+    for entry in spec.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+        let (format, mode) = entry
+            .split_once('=')
+            .ok_or_else(|| format!("Invalid lyrics storage entry '{}'. Expected format=mode", entry))?;
+        let format = format.trim().trim_start_matches('.').to_lowercase();
+        if format.is_empty() {
+            return Err(format!("Missing format in lyrics storage entry '{}'", entry));
+        }
+        overrides.insert(format, mode.parse::<LyricsStorage>()?);
+    }
+
+    Ok(overrides)
+}
+
+/// Lyrics found in an audio file's own tags
+#[derive(Debug, Clone, Default)]
+pub struct EmbeddedLyrics {
+    pub plain: Option<String>,
+    pub synced: Option<String>,
+}
 
 pub struct LyricsManager;
 
@@ -92,10 +227,26 @@ impl LyricsManager {
         let _ = remove_file(&txt_path);
         let _ = remove_file(&lrc_path);
 
-        write(lrc_path, "[au: instrumental]")?;
+        write(lrc_path, INSTRUMENTAL_MARKER)?;
         Ok(())
     }
 
+    /// Remove the `.lrc` and `.txt` sidecars of a track, leaving embedded lyrics untouched.
+    /// Returns whether any file was removed.
+    pub fn remove_sidecar_lyrics(&self, track_path: &str) -> Result<bool> {
+        let mut removed = false;
+
+        for path in [self.build_lrc_path(track_path)?, self.build_txt_path(track_path)?] {
+            match remove_file(&path) {
+                Ok(()) => removed = true,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => return Err(e.into()),
+            }
+        }
+
+        Ok(removed)
+    }
+
     fn build_txt_path(&self, track_path: &str) -> Result<PathBuf> {
         let path = Path::new(track_path);
         let parent_path = path.parent().ok_or_else(|| anyhow::anyhow!(
@@ -130,27 +281,521 @@ impl LyricsManager {
         Ok(lrc_path)
     }
 
-    // Future: Add embedding functionality for MP3/FLAC files
-    pub fn embed_lyrics(&self, track_path: &str, plain_lyrics: &str, synced_lyrics: &str) -> Result<()> {
-        if track_path.to_lowercase().ends_with(".mp3") {
-            self.embed_lyrics_mp3(track_path, plain_lyrics, synced_lyrics)
-        } else if track_path.to_lowercase().ends_with(".flac") {
-            self.embed_lyrics_flac(track_path, plain_lyrics, synced_lyrics)
-        } else {
-            warn!("Embedding not supported for file type: {}", track_path);
-            Ok(())
+    /// Embed lyrics into the audio file's own tags.
+    ///
+    /// MP3 files get a USLT frame with the plain text and a SYLT frame with the
+    /// timed lines; FLAC, Ogg and Opus files get Vorbis `LYRICS` and `UNSYNCEDLYRICS`
+    /// comments; MP4/M4A files get the iTunes `©lyr` atom.
+    pub fn embed_lyrics(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let plain_lyrics = plain_lyrics.filter(|l| !l.trim().is_empty());
+        let synced_lyrics = synced_lyrics.filter(|l| !l.trim().is_empty());
+
+        if plain_lyrics.is_none() && synced_lyrics.is_none() {
+            debug!("No lyrics to embed for: {}", track_path);
+            return Ok(());
+        }
+
+        self.write_lyrics_tags(track_path, plain_lyrics, synced_lyrics)
+    }
+
+    /// Remove lyrics from the audio file's tags, leaving its other tags alone.
+    /// Returns whether there were any.
+    pub fn remove_embedded_lyrics(&self, track_path: &str) -> Result<bool> {
+        let embedded = self.read_embedded_lyrics(track_path)?;
+        if embedded.plain.is_none() && embedded.synced.is_none() {
+            return Ok(false);
+        }
+
+        self.write_lyrics_tags(track_path, None, None)?;
+        debug!("Removed embedded lyrics from: {}", track_path);
+        Ok(true)
+    }
+
+    /// Replace the lyrics tags of a file; without lyrics, existing ones are only removed
+    fn write_lyrics_tags(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        match file_extension(track_path).as_deref() {
+            Some("mp3") => self.embed_lyrics_mp3(track_path, plain_lyrics, synced_lyrics),
+            Some("flac") => self.embed_lyrics_flac(track_path, plain_lyrics, synced_lyrics),
+            Some("m4a") | Some("mp4") | Some("alac") => {
+                self.embed_lyrics_mp4(track_path, plain_lyrics, synced_lyrics)
+            }
+            Some("ogg") => self.embed_lyrics_ogg(track_path, plain_lyrics, synced_lyrics),
+            Some("opus") => self.embed_lyrics_opus(track_path, plain_lyrics, synced_lyrics),
+            _ => {
+                warn!("Embedding not supported for file type: {}", track_path);
+                Ok(())
+            }
+        }
+    }
+
+    /// Read lyrics stored in the audio file's tags (USLT/SYLT, Vorbis `LYRICS`/`UNSYNCEDLYRICS`, `©lyr`).
+    ///
+    /// Text fields holding LRC timestamps are returned as synced lyrics, SYLT frames are
+    /// converted back to LRC.
+    pub fn read_embedded_lyrics(&self, track_path: &str) -> Result<EmbeddedLyrics> {
+        let parse_options = ParseOptions::new().read_properties(false);
+        Ok(self
+            .read_with_embedded_lyrics(track_path, parse_options)?
+            .map(|(_, embedded)| embedded)
+            .unwrap_or_default())
+    }
+
+    /// Parse an audio file once for both its generic tags/properties and its embedded lyrics.
+    ///
+    /// The format-specific parse is needed because the generic tag drops SYLT frames.
+    /// Returns `None` for formats lyrics can't be embedded in.
+    pub fn read_with_embedded_lyrics(
+        &self,
+        track_path: &str,
+        parse_options: ParseOptions,
+    ) -> Result<Option<(TaggedFile, EmbeddedLyrics)>> {
+        let mut file = File::open(track_path)?;
+        let mut texts: Vec<String> = Vec::new();
+        let mut sylt_lyrics = None;
+
+        let tagged_file: TaggedFile = match file_extension(track_path).as_deref() {
+            Some("mp3") => {
+                let mpeg_file = MpegFile::read_from(&mut file, parse_options)?;
+                if let Some(tag) = mpeg_file.id3v2() {
+                    texts.extend(tag.unsync_text().map(|frame| frame.content.clone()));
+                    sylt_lyrics = read_sylt_as_lrc(tag);
+                }
+                mpeg_file.into()
+            }
+            Some("flac") => {
+                let flac_file = FlacFile::read_from(&mut file, parse_options)?;
+                if let Some(comments) = flac_file.vorbis_comments() {
+                    texts.extend(read_vorbis_lyrics(comments));
+                }
+                flac_file.into()
+            }
+            Some("m4a") | Some("mp4") | Some("alac") => {
+                let mp4_file = Mp4File::read_from(&mut file, parse_options)?;
+                if let Some(ilst) = mp4_file.ilst() {
+                    if let Some(atom) = ilst.get(&AtomIdent::Fourcc(MP4_LYRICS_ATOM)) {
+                        texts.extend(atom.data().filter_map(|data| match data {
+                            AtomData::UTF8(text) => Some(text.clone()),
+                            _ => None,
+                        }));
+                    }
+                }
+                mp4_file.into()
+            }
+            Some("ogg") => {
+                let vorbis_file = VorbisFile::read_from(&mut file, parse_options)?;
+                texts.extend(read_vorbis_lyrics(vorbis_file.vorbis_comments()));
+                vorbis_file.into()
+            }
+            Some("opus") => {
+                let opus_file = OpusFile::read_from(&mut file, parse_options)?;
+                texts.extend(read_vorbis_lyrics(opus_file.vorbis_comments()));
+                opus_file.into()
+            }
+            _ => return Ok(None),
+        };
+
+        let mut embedded = EmbeddedLyrics {
+            plain: None,
+            synced: sylt_lyrics,
+        };
+
+        for text in texts.into_iter().filter(|t| !t.trim().is_empty()) {
+            if is_synced_lyrics(&text) {
+                embedded.synced.get_or_insert(text);
+            } else {
+                embedded.plain.get_or_insert(text);
+            }
         }
+
+        Ok(Some((tagged_file, embedded)))
     }
 
-    fn embed_lyrics_mp3(&self, _track_path: &str, _plain_lyrics: &str, _synced_lyrics: &str) -> Result<()> {
-        // TODO: Implement MP3 ID3v2 embedding
-        warn!("MP3 embedding not yet implemented");
+    fn embed_lyrics_mp3(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let mut file = File::open(track_path)?;
+        let mut mpeg_file = MpegFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| embed_error(track_path, e))?;
+        drop(file);
+
+        let mut tag = mpeg_file.remove_id3v2().unwrap_or_else(Id3v2Tag::new);
+
+        // Replace whatever lyrics frames the file already carried
+        let _ = tag.remove(&FrameId::Valid(Cow::Borrowed(USLT_FRAME_ID)));
+        let _ = tag.remove(&FrameId::Valid(Cow::Borrowed(SYLT_FRAME_ID)));
+
+        let unsynced_text = plain_lyrics
+            .map(str::to_string)
+            .or_else(|| synced_lyrics.map(strip_timestamps));
+
+        if let Some(text) = unsynced_text {
+            tag.insert(Frame::UnsynchronizedText(UnsynchronizedTextFrame::new(
+                TextEncoding::UTF8,
+                LYRICS_LANGUAGE,
+                String::new(),
+                text,
+            )));
+        }
+
+        if let Some(synced) = synced_lyrics {
+            let timed_lines = parse_timed_lines(synced);
+            if timed_lines.is_empty() {
+                warn!("Synced lyrics have no usable timestamps, skipping SYLT for: {}", track_path);
+            } else {
+                let sylt = SynchronizedTextFrame::new(
+                    TextEncoding::UTF8,
+                    LYRICS_LANGUAGE,
+                    TimestampFormat::MS,
+                    SyncTextContentType::Lyrics,
+                    None,
+                    timed_lines,
+                );
+                let data = sylt.as_bytes().map_err(|e| embed_error(track_path, e))?;
+                tag.insert(Frame::Binary(BinaryFrame::new(
+                    FrameId::Valid(Cow::Borrowed(SYLT_FRAME_ID)),
+                    data,
+                )));
+            }
+        }
+
+        mpeg_file.set_id3v2(tag);
+        mpeg_file
+            .save_to_path(track_path, WriteOptions::default())
+            .map_err(|e| embed_error(track_path, e))?;
+
+        debug!("Embedded lyrics into MP3: {}", track_path);
+        Ok(())
+    }
+
+    fn embed_lyrics_flac(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let mut file = File::open(track_path)?;
+        let mut flac_file = FlacFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| embed_error(track_path, e))?;
+        drop(file);
+
+        if flac_file.vorbis_comments().is_none() {
+            flac_file.set_vorbis_comments(VorbisComments::default());
+        }
+        if let Some(comments) = flac_file.vorbis_comments_mut() {
+            apply_vorbis_lyrics(comments, plain_lyrics, synced_lyrics);
+        }
+
+        flac_file
+            .save_to_path(track_path, WriteOptions::default())
+            .map_err(|e| embed_error(track_path, e))?;
+
+        debug!("Embedded lyrics into FLAC: {}", track_path);
+        Ok(())
+    }
+
+    fn embed_lyrics_mp4(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let mut file = File::open(track_path)?;
+        let mut mp4_file = Mp4File::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| embed_error(track_path, e))?;
+        drop(file);
+
+        if mp4_file.ilst().is_none() {
+            mp4_file.set_ilst(Ilst::new());
+        }
+        if let Some(ilst) = mp4_file.ilst_mut() {
+            let lyrics_ident = AtomIdent::Fourcc(MP4_LYRICS_ATOM);
+            let _ = ilst.remove(&lyrics_ident);
+
+            // ©lyr holds a single text value, so prefer the synced text like Vorbis LYRICS
+            if let Some(lyrics) = synced_lyrics.or(plain_lyrics) {
+                ilst.insert(Atom::new(lyrics_ident, AtomData::UTF8(lyrics.to_string())));
+            }
+        }
+
+        mp4_file
+            .save_to_path(track_path, WriteOptions::default())
+            .map_err(|e| embed_error(track_path, e))?;
+
+        debug!("Embedded lyrics into MP4: {}", track_path);
+        Ok(())
+    }
+
+    fn embed_lyrics_ogg(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let mut file = File::open(track_path)?;
+        let mut vorbis_file = VorbisFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| embed_error(track_path, e))?;
+        drop(file);
+
+        apply_vorbis_lyrics(vorbis_file.vorbis_comments_mut(), plain_lyrics, synced_lyrics);
+
+        vorbis_file
+            .save_to_path(track_path, WriteOptions::default())
+            .map_err(|e| embed_error(track_path, e))?;
+
+        debug!("Embedded lyrics into Ogg Vorbis: {}", track_path);
         Ok(())
     }
 
-    fn embed_lyrics_flac(&self, _track_path: &str, _plain_lyrics: &str, _synced_lyrics: &str) -> Result<()> {
-        // TODO: Implement FLAC Vorbis comments embedding
-        warn!("FLAC embedding not yet implemented");
+    fn embed_lyrics_opus(
+        &self,
+        track_path: &str,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+    ) -> Result<()> {
+        let mut file = File::open(track_path)?;
+        let mut opus_file = OpusFile::read_from(&mut file, ParseOptions::new())
+            .map_err(|e| embed_error(track_path, e))?;
+        drop(file);
+
+        apply_vorbis_lyrics(opus_file.vorbis_comments_mut(), plain_lyrics, synced_lyrics);
+
+        opus_file
+            .save_to_path(track_path, WriteOptions::default())
+            .map_err(|e| embed_error(track_path, e))?;
+
+        debug!("Embedded lyrics into Opus: {}", track_path);
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+const USLT_FRAME_ID: &str = "USLT";
+const SYLT_FRAME_ID: &str = "SYLT";
+const LYRICS_LANGUAGE: [u8; 3] = *b"XXX";
+const MP4_LYRICS_ATOM: [u8; 4] = *b"\xa9lyr";
+
+/// Lowercased file extension of a track path, if any
+fn file_extension(track_path: &str) -> Option<String> {
+    Path::new(track_path)
+        .extension()
+        .and_then(|ext| ext.to_str())
+        .map(|ext| ext.to_lowercase())
+}
+
+/// Write lyrics into a Vorbis comment block, replacing any existing values.
+///
+/// `LYRICS` holds the synced text when available (most players read LRC from it),
+/// `UNSYNCEDLYRICS` always holds the plain text.
+fn apply_vorbis_lyrics(
+    comments: &mut VorbisComments,
+    plain_lyrics: Option<&str>,
+    synced_lyrics: Option<&str>,
+) {
+    let _ = comments.remove("LYRICS");
+    let _ = comments.remove("UNSYNCEDLYRICS");
+
+    if let Some(lyrics) = synced_lyrics.or(plain_lyrics) {
+        comments.insert(String::from("LYRICS"), lyrics.to_string());
+    }
+
+    let unsynced_text = plain_lyrics
+        .map(str::to_string)
+        .or_else(|| synced_lyrics.map(strip_timestamps));
+    if let Some(text) = unsynced_text {
+        comments.insert(String::from("UNSYNCEDLYRICS"), text);
+    }
+}
+
+/// Collect the lyrics values of a Vorbis comment block, `LYRICS` first
+fn read_vorbis_lyrics(comments: &VorbisComments) -> Vec<String> {
+    comments
+        .get_all("LYRICS")
+        .chain(comments.get_all("UNSYNCEDLYRICS"))
+        .map(str::to_string)
+        .collect()
+}
+
+/// Convert the first SYLT lyrics frame of an ID3v2 tag back into LRC text
+fn read_sylt_as_lrc(tag: &Id3v2Tag) -> Option<String> {
+    let sylt_id = FrameId::Valid(Cow::Borrowed(SYLT_FRAME_ID));
+
+    tag.into_iter()
+        .filter(|frame| frame.id() == &sylt_id)
+        .find_map(|frame| {
+            let Frame::Binary(binary) = frame else { return None };
+            let sylt = SynchronizedTextFrame::parse(&binary.data, frame.flags()).ok()?;
+            if sylt.timestamp_format != TimestampFormat::MS || sylt.content.is_empty() {
+                return None;
+            }
+
+            let lines: Vec<String> = sylt
+                .content
+                .iter()
+                .map(|(millis, text)| format!("{}{}", format_lrc_timestamp(*millis), text.trim_start()))
+                .collect();
+            Some(lines.join("\n"))
+        })
+}
+
+/// Format milliseconds as an LRC time tag, e.g. `[01:02.35]`
+pub fn format_lrc_timestamp(millis: u32) -> String {
+    let centis = (millis / 10) % 100;
+    let seconds = (millis / 1000) % 60;
+    let minutes = millis / 60_000;
+    format!("[{:02}:{:02}.{:02}]", minutes, seconds, centis)
+}
+
+/// Whether lyrics text is the instrumental marker written by `LyricsManager::save_instrumental`
+pub fn is_instrumental_marker(text: &str) -> bool {
+    text.trim().eq_ignore_ascii_case(INSTRUMENTAL_MARKER)
+}
+
+/// Whether a lyrics text carries LRC time tags
+pub fn is_synced_lyrics(lyrics: &str) -> bool {
+    lyrics.lines().any(|line| {
+        let line = line.trim_start();
+        line.starts_with('[')
+            && line[1..].starts_with(|c: char| c.is_ascii_digit())
+            && line.contains(':')
+            && line.contains(']')
+    })
+}
+
+/// Parse LRC text into `(milliseconds, text)` pairs sorted by time, as stored in SYLT.
+pub fn parse_timed_lines(synced_lyrics: &str) -> Vec<(u32, String)> {
+    match lrc::Lyrics::from_str(synced_lyrics) {
+        Ok(lyrics) => lyrics
+            .get_timed_lines()
+            .iter()
+            .map(|(time_tag, text)| {
+                let millis = time_tag.get_timestamp().clamp(0, u32::MAX as i64) as u32;
+                (millis, text.to_string())
+            })
+            .collect(),
+        Err(e) => {
+            debug!("Failed to parse synced lyrics: {}", e);
+            Vec::new()
+        }
+    }
+}
+
+/// Turn LRC text into plain text by dropping time tags and metadata-only lines.
+pub fn strip_timestamps(synced_lyrics: &str) -> String {
+    synced_lyrics
+        .lines()
+        .filter_map(|line| {
+            let mut rest = line.trim();
+            let mut has_time_tag = false;
+            let mut has_id_tag = false;
+
+            while rest.starts_with('[') {
+                let Some(end) = rest.find(']') else { break };
+                if rest[1..end].starts_with(|c: char| c.is_ascii_digit()) {
+                    has_time_tag = true;
+                } else {
+                    has_id_tag = true;
+                }
+                rest = rest[end + 1..].trim_start();
+            }
+
+            // Lines like [ar: Artist] carry metadata, not lyrics
+            if has_id_tag && !has_time_tag {
+                None
+            } else {
+                Some(rest.to_string())
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn embed_error(track_path: &str, error: impl std::fmt::Display) -> anyhow::Error {
+    LyricsError::EmbedFailed {
+        reason: format!("{}: {}", track_path, error),
+    }
+    .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    const SYNCED: &str = "[ar: Artist]\n[ti: Title]\n[00:01.50] First line\n[00:03.00]Second line\n[00:00.25] Intro\n";
+
+    #[test]
+    fn test_parse_timed_lines_sorted_in_ms() {
+        let lines = parse_timed_lines(SYNCED);
+        assert_eq!(
+            lines,
+            vec![
+                (250, "Intro".to_string()),
+                (1500, "First line".to_string()),
+                (3000, "Second line".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_strip_timestamps_drops_metadata() {
+        assert_eq!(strip_timestamps(SYNCED), "First line\nSecond line\nIntro");
+    }
+
+    #[test]
+    fn test_format_lrc_timestamp() {
+        assert_eq!(format_lrc_timestamp(0), "[00:00.00]");
+        assert_eq!(format_lrc_timestamp(62_350), "[01:02.35]");
+    }
+
+    #[test]
+    fn test_is_synced_lyrics() {
+        assert!(is_synced_lyrics(SYNCED));
+        assert!(!is_synced_lyrics("[Chorus]\nJust words"));
+    }
+
+    #[test]
+    fn test_is_instrumental_marker() {
+        assert!(is_instrumental_marker(INSTRUMENTAL_MARKER));
+        assert!(is_instrumental_marker("[AU: Instrumental]\n"));
+        assert!(!is_instrumental_marker(SYNCED));
+    }
+
+    /// A few silent MPEG-1 Layer III frames (128 kbps, 44.1 kHz) without tags
+    fn silent_mp3() -> (TestDir, PathBuf) {
+        let dir = TestDir::new("lyrics");
+        let mut frame = vec![0u8; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+        let path = dir.path().join("song.mp3");
+        std::fs::write(&path, frame.repeat(10)).unwrap();
+        (dir, path)
+    }
+
+    #[test]
+    fn test_embedded_lyrics_round_trip_and_removal() {
+        let (_dir, path) = silent_mp3();
+        let track_path = path.to_str().unwrap();
+        let manager = LyricsManager::new();
+
+        manager.embed_lyrics(track_path, Some("Words"), Some("[00:01.00]Words")).unwrap();
+        let embedded = manager.read_embedded_lyrics(track_path).unwrap();
+        assert_eq!(embedded.plain.as_deref(), Some("Words"));
+        assert_eq!(embedded.synced.as_deref(), Some("[00:01.00]Words"));
+
+        assert!(manager.remove_embedded_lyrics(track_path).unwrap());
+        let embedded = manager.read_embedded_lyrics(track_path).unwrap();
+        assert!(embedded.plain.is_none() && embedded.synced.is_none());
+        assert!(!manager.remove_embedded_lyrics(track_path).unwrap());
+    }
+}
@@ -3,8 +3,16 @@
 //! This module contains functionality for working with files:
 //! - Music file scanning and metadata extraction
 //! - Lyrics file processing and validation
+//! - Linting of `.lrc` sidecar files
+//! - Shifting the timing of synced lyrics
+//! - Reading M3U/M3U8 and PLS playlists
+//! - Checking that lyrics timings fit the audio file
 
 pub mod scanner;
 pub mod lyrics;
+pub mod lrc_lint;
+pub mod lrc_shift;
+pub mod duration_check;
+pub mod playlist;
 
 // Re-export main types
@@ -0,0 +1,173 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+use tracing::debug;
+
+/// One entry of an M3U/M3U8 or PLS playlist
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistEntry {
+    pub path: PathBuf,
+    /// Title from `#EXTINF` or `TitleN=`, with any `Artist - ` prefix split off
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    /// Length in seconds; playlists use -1 for unknown
+    pub duration: Option<f64>,
+}
+
+/// Whether a file extension is a playlist format `read_playlist` understands
+pub fn is_playlist_extension(extension: &str) -> bool {
+    matches!(extension.to_lowercase().as_str(), "m3u" | "m3u8" | "pls")
+}
+
+/// Read a playlist file. Relative entries are resolved against the playlist's directory;
+/// stream URLs are skipped.
+pub fn read_playlist(path: &Path) -> Result<Vec<PlaylistEntry>> {
+    let bytes = std::fs::read(path)?;
+    let content = decode(&bytes);
+    let base_dir = path.parent().unwrap_or(Path::new("."));
+
+    let is_pls = path
+        .extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("pls"));
+
+    Ok(if is_pls {
+        parse_pls(&content, base_dir)
+    } else {
+        parse_m3u(&content, base_dir)
+    })
+}
+
+/// `.m3u8` is UTF-8; plain `.m3u` files are often Latin-1
+fn decode(bytes: &[u8]) -> String {
+    let bytes = bytes.strip_prefix(&[0xEF, 0xBB, 0xBF]).unwrap_or(bytes);
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        Err(_) => bytes.iter().map(|&b| b as char).collect(),
+    }
+}
+
+pub fn parse_m3u(content: &str, base_dir: &Path) -> Vec<PlaylistEntry> {
+    let mut entries = Vec::new();
+    let mut pending_info: Option<(Option<f64>, Option<String>)> = None;
+
+    for line in content.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(info) = line.strip_prefix("#EXTINF:") {
+            // #EXTINF:<seconds> [attributes],<display title>
+            let (length, title) = info.split_once(',').unwrap_or((info, ""));
+            let length = length.split_whitespace().next().unwrap_or("");
+            pending_info = Some((parse_length(length), Some(title.trim().to_string()).filter(|t| !t.is_empty())));
+            continue;
+        }
+        if line.starts_with('#') {
+            continue;
+        }
+
+        let info = pending_info.take();
+        if let Some(path) = resolve_entry(line, base_dir) {
+            let (duration, display) = info.unwrap_or((None, None));
+            entries.push(entry(path, display, duration));
+        }
+    }
+
+    entries
+}
+
+pub fn parse_pls(content: &str, base_dir: &Path) -> Vec<PlaylistEntry> {
+    let mut files: Vec<(u32, String)> = Vec::new();
+    let mut titles: Vec<(u32, String)> = Vec::new();
+    let mut lengths: Vec<(u32, String)> = Vec::new();
+
+    for line in content.lines().map(str::trim) {
+        let Some((key, value)) = line.split_once('=') else { continue };
+        let key = key.trim().to_lowercase();
+        let value = value.trim().to_string();
+
+        let numbered = |prefix: &str| key.strip_prefix(prefix).and_then(|n| n.parse::<u32>().ok());
+        if let Some(n) = numbered("file") {
+            files.push((n, value));
+        } else if let Some(n) = numbered("title") {
+            titles.push((n, value));
+        } else if let Some(n) = numbered("length") {
+            lengths.push((n, value));
+        }
+    }
+
+    files.sort_by_key(|(n, _)| *n);
+    let lookup = |list: &[(u32, String)], n: u32| list.iter().find(|(i, _)| *i == n).map(|(_, v)| v.clone());
+
+    files
+        .into_iter()
+        .filter_map(|(n, file)| {
+            let path = resolve_entry(&file, base_dir)?;
+            let display = lookup(&titles, n).filter(|t| !t.is_empty());
+            let duration = lookup(&lengths, n).and_then(|l| parse_length(&l));
+            Some(entry(path, display, duration))
+        })
+        .collect()
+}
+
+fn entry(path: PathBuf, display: Option<String>, duration: Option<f64>) -> PlaylistEntry {
+    let (artist, title) = match display {
+        Some(display) => match display.split_once(" - ") {
+            Some((artist, title)) => (Some(artist.trim().to_string()), Some(title.trim().to_string())),
+            None => (None, Some(display)),
+        },
+        None => (None, None),
+    };
+
+    PlaylistEntry {
+        path,
+        title,
+        artist,
+        duration,
+    }
+}
+
+fn parse_length(value: &str) -> Option<f64> {
+    value.trim().parse::<f64>().ok().filter(|seconds| *seconds > 0.0)
+}
+
+fn resolve_entry(location: &str, base_dir: &Path) -> Option<PathBuf> {
+    if location.starts_with("file://") {
+        return url::Url::parse(location).ok()?.to_file_path().ok();
+    }
+    if location.contains("://") {
+        debug!("Skipping playlist stream entry: {}", location);
+        return None;
+    }
+
+    // Playlists written on Windows use backslashes
+    let location = if cfg!(windows) { location.to_string() } else { location.replace('\\', "/") };
+    let path = PathBuf::from(location);
+    Some(if path.is_absolute() { path } else { base_dir.join(path) })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_m3u_with_extinf() {
+        let content = "#EXTM3U\n#EXTINF:215,Queen - Bohemian Rhapsody\nMusic/queen.mp3\n\n/abs/other.flac\nhttp://radio/stream\n#EXTINF:-1,Untitled\nfile:///tmp/a%20b.ogg\n";
+        let entries = parse_m3u(content, Path::new("/lists"));
+
+        assert_eq!(entries.len(), 3);
+        assert_eq!(entries[0].path, PathBuf::from("/lists/Music/queen.mp3"));
+        assert_eq!(entries[0].artist.as_deref(), Some("Queen"));
+        assert_eq!(entries[0].title.as_deref(), Some("Bohemian Rhapsody"));
+        assert_eq!(entries[0].duration, Some(215.0));
+        assert_eq!(entries[1].title, None);
+        assert_eq!(entries[2].path, PathBuf::from("/tmp/a b.ogg"));
+        assert_eq!((entries[2].artist.as_deref(), entries[2].duration), (None, None));
+    }
+
+    #[test]
+    fn test_parse_pls() {
+        let content = "[playlist]\nFile2=b.mp3\nTitle2=Artist - B\nLength2=120\nFile1=a.mp3\nNumberOfEntries=2\n";
+        let entries = parse_pls(content, Path::new("/lists"));
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, PathBuf::from("/lists/a.mp3"));
+        assert_eq!(entries[1].title.as_deref(), Some("B"));
+        assert_eq!(entries[1].duration, Some(120.0));
+    }
+}
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use globwalk::{glob, DirEntry};
+use lofty::config::ParseOptions;
 use lofty::error::LoftyError;
 use lofty::file::AudioFile;
 use lofty::file::TaggedFileExt;
@@ -8,11 +9,94 @@ use lofty::read_from_path;
 use lofty::tag::Accessor;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::Read;
 use std::path::{Path, PathBuf};
 use std::process::Command;
+use std::time::UNIX_EPOCH;
 use thiserror::Error;
 use tracing::{debug, warn};
 
+use crate::core::files::lyrics::{is_instrumental_marker, EmbeddedLyrics, LyricsManager};
+
+/// Where a track's lyrics were found during scanning
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum LyricsSource {
+    /// `.lrc`/`.txt` file next to the track
+    Sidecar,
+    /// Tags inside the audio file
+    Embedded,
+}
+
+impl LyricsSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LyricsSource::Sidecar => "sidecar",
+            LyricsSource::Embedded => "embedded",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "sidecar" => Some(LyricsSource::Sidecar),
+            "embedded" => Some(LyricsSource::Embedded),
+            _ => None,
+        }
+    }
+}
+
+/// Cheap change detection for an audio file and its lyrics sidecars
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub struct FileFingerprint {
+    /// Modification time in milliseconds since the Unix epoch
+    pub mtime: i64,
+    /// File size in bytes
+    pub size: i64,
+    /// Modification times of the `.lrc`/`.txt` sidecars, e.g. `lrc=1700000000000;txt=`
+    pub sidecars: String,
+}
+
+impl FileFingerprint {
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let metadata = std::fs::metadata(path).ok()?;
+        let sidecar_mtime = |ext: &str| {
+            modified_millis(&path.with_extension(ext))
+                .map(|mtime| mtime.to_string())
+                .unwrap_or_default()
+        };
+
+        Some(FileFingerprint {
+            mtime: modified_millis(path)?,
+            size: metadata.len() as i64,
+            sidecars: format!("lrc={};txt={}", sidecar_mtime("lrc"), sidecar_mtime("txt")),
+        })
+    }
+}
+
+fn modified_millis(path: &Path) -> Option<i64> {
+    let modified = std::fs::metadata(path).ok()?.modified().ok()?;
+    let duration = modified.duration_since(UNIX_EPOCH).ok()?;
+    Some(duration.as_millis() as i64)
+}
+
+/// SHA-256 of the file contents, hex encoded
+pub fn compute_content_hash(path: &Path) -> Result<String> {
+    let mut file = File::open(path)?;
+    let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+    let mut buffer = vec![0u8; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        context.update(&buffer[..read]);
+    }
+
+    Ok(data_encoding::HEXLOWER.encode(context.finish().as_ref()))
+}
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct Track {
     pub file_path: String,
@@ -25,6 +109,17 @@ pub struct Track {
     pub txt_lyrics: Option<String>,
     pub lrc_lyrics: Option<String>,
     pub track_number: Option<u32>,
+    #[serde(default)]
+    pub txt_lyrics_source: Option<LyricsSource>,
+    #[serde(default)]
+    pub lrc_lyrics_source: Option<LyricsSource>,
+    #[serde(default)]
+    pub fingerprint: Option<FileFingerprint>,
+    #[serde(default)]
+    pub content_hash: Option<String>,
+    /// Set when the .lrc sidecar holds the instrumental marker instead of lyrics
+    #[serde(default)]
+    pub instrumental: bool,
 }
 
 #[derive(Error, Debug)]
@@ -65,18 +160,37 @@ impl Track {
             txt_lyrics,
             lrc_lyrics,
             track_number,
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            fingerprint: None,
+            content_hash: None,
+            instrumental: false,
         }
     }
 
     pub fn new_from_path(path: &Path) -> Result<Track> {
         let file_path = path.display().to_string();
+        // Taken before parsing so a file modified mid-scan is picked up next time
+        let fingerprint = FileFingerprint::from_path(path);
         // Avoid panics on non-UTF8 or edge-case paths by using lossy conversion and a safe fallback
         let file_name = path
             .file_name()
             .map(|os| os.to_string_lossy().into_owned())
             .unwrap_or_else(|| path.display().to_string());
-        let tagged_file = read_from_path(&file_path)
-            .map_err(|err| TrackError::ParseFailed(file_path.to_owned(), err))?;
+        // Formats that can carry lyrics are parsed once for both tags and embedded lyrics
+        let (tagged_file, embedded) = match LyricsManager::new()
+            .read_with_embedded_lyrics(&file_path, ParseOptions::new())
+        {
+            Ok(Some((tagged_file, embedded))) => (tagged_file, Some(embedded)),
+            result => {
+                if let Err(e) = result {
+                    debug!("Failed to read embedded lyrics from {}: {}", file_path, e);
+                }
+                let tagged_file = read_from_path(&file_path)
+                    .map_err(|err| TrackError::ParseFailed(file_path.to_owned(), err))?;
+                (tagged_file, None)
+            }
+        };
         let tag = tagged_file
             .primary_tag()
             .ok_or(TrackError::PrimaryTagNotFound(file_path.to_owned()))?
@@ -113,12 +227,36 @@ impl Track {
             None,
             track_number,
         );
+        track.fingerprint = fingerprint;
         track.txt_lyrics = track.get_txt_lyrics();
         track.lrc_lyrics = track.get_lrc_lyrics();
+        if track.lrc_lyrics.as_deref().is_some_and(is_instrumental_marker) {
+            track.lrc_lyrics = None;
+            track.instrumental = true;
+        }
+        track.txt_lyrics_source = track.txt_lyrics.as_ref().map(|_| LyricsSource::Sidecar);
+        track.lrc_lyrics_source = track.lrc_lyrics.as_ref().map(|_| LyricsSource::Sidecar);
+
+        // Sidecars take precedence; only fall back to tags for what's missing
+        if let Some(embedded) = embedded.filter(|_| !track.instrumental) {
+            track.apply_embedded_lyrics(embedded);
+        }
 
         Ok(track)
     }
 
+    fn apply_embedded_lyrics(&mut self, embedded: EmbeddedLyrics) {
+        if self.lrc_lyrics.is_none() && embedded.synced.is_some() {
+            self.lrc_lyrics = embedded.synced;
+            self.lrc_lyrics_source = Some(LyricsSource::Embedded);
+        }
+
+        if self.txt_lyrics.is_none() && embedded.plain.is_some() {
+            self.txt_lyrics = embedded.plain;
+            self.txt_lyrics_source = Some(LyricsSource::Embedded);
+        }
+    }
+
     fn get_txt_path(&self) -> String {
         // Build path safely without unwraps; just change extension to .txt
         let mut path = PathBuf::from(&self.file_path);
@@ -135,10 +273,7 @@ impl Track {
     }
 
     fn get_lrc_path(&self) -> String {
-        // Build path safely without unwraps; just change extension to .lrc
-        let mut path = PathBuf::from(&self.file_path);
-        path.set_extension("lrc");
-        path.to_string_lossy().into_owned()
+        lrc_sidecar_path(&self.file_path).to_string_lossy().into_owned()
     }
 
     fn get_lrc_lyrics(&self) -> Option<String> {
@@ -150,6 +285,14 @@ impl Track {
     }
 }
 
+/// Path of the `.lrc` sidecar for an audio file
+pub fn lrc_sidecar_path(file_path: &str) -> PathBuf {
+    // Build path safely without unwraps; just change extension to .lrc
+    let mut path = PathBuf::from(file_path);
+    path.set_extension("lrc");
+    path
+}
+
 pub struct Scanner;
 
 impl Scanner {
@@ -158,6 +301,12 @@ impl Scanner {
     }
 
     pub async fn scan_directory(&self, directory: &Path, extensions: &Option<Vec<String>>) -> Result<Vec<Track>> {
+        let paths = self.list_audio_files(directory, extensions)?;
+        self.scan_paths(&paths, false).await
+    }
+
+    /// Find audio files below `directory` without parsing them
+    pub fn list_audio_files(&self, directory: &Path, extensions: &Option<Vec<String>>) -> Result<Vec<PathBuf>> {
         let directory_str = directory.to_string_lossy();
         debug!("Scanning directory: {}", directory_str);
 
@@ -181,14 +330,20 @@ impl Scanner {
 
         debug!("Found {} audio files", entries.len());
 
-        let tracks = self.load_tracks_from_entries(&entries)?;
-        Ok(tracks)
+        Ok(entries.into_iter().map(|entry| entry.into_path()).collect())
     }
 
-    fn load_tracks_from_entries(&self, entries: &[DirEntry]) -> Result<Vec<Track>> {
-        let track_results: Vec<Result<Track>> = entries
+    /// Parse the given files in parallel, optionally hashing their contents
+    pub async fn scan_paths(&self, paths: &[PathBuf], with_content_hash: bool) -> Result<Vec<Track>> {
+        let track_results: Vec<Result<Track>> = paths
             .par_iter()
-            .map(|file| Track::new_from_path(file.path()))
+            .map(|path| {
+                let mut track = Track::new_from_path(path)?;
+                if with_content_hash {
+                    track.content_hash = Some(compute_content_hash(path)?);
+                }
+                Ok(track)
+            })
             .collect();
 
         let mut tracks: Vec<Track> = vec![];
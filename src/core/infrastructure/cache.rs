@@ -2,7 +2,8 @@ use anyhow::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tracing::{debug, info, warn};
 use async_trait::async_trait;
@@ -10,11 +11,21 @@ use redis::{AsyncCommands, Client as RedisClient};
 
 use crate::core::services::lrclib::LyricsResponse;
 
+/// A cached answer for a track: either lyrics or a remembered "not found"
+#[derive(Debug, Clone)]
+pub enum CachedLyrics {
+    Found(LyricsResponse),
+    Missing,
+}
+
 // Generic trait for cache implementations
 #[async_trait]
 pub trait LyricsCacheInterface: Send + Sync {
-    async fn get(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<LyricsResponse>;
+    /// Look up a track, including negative ("known missing") entries
+    async fn lookup(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<CachedLyrics>;
     async fn put(&mut self, title: &str, artist: &str, album: &str, duration: f64, lyrics: LyricsResponse) -> Result<()>;
+    /// Remember that no lyrics exist for a track; expires sooner than regular entries
+    async fn put_missing(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Result<()>;
     async fn clear(&mut self) -> Result<()>;
     fn get_stats(&self) -> CacheStats;
     async fn cleanup_old_entries(&mut self) -> Result<()>;
@@ -24,49 +35,187 @@ pub trait LyricsCacheInterface: Send + Sync {
 // Re-export the trait with a cleaner name
 pub use LyricsCacheInterface as CacheService;
 
+const INDEX_FILE: &str = "cache_index.json";
+const ENTRIES_DIR: &str = "entries";
+const INDEX_VERSION: u32 = 2;
+
+/// Bookkeeping for one cached track; the lyrics live in their own file under `entries/`
 #[derive(Serialize, Deserialize, Clone)]
 struct CacheEntry {
-    lyrics: LyricsResponse,
     cached_at: u64,
     access_count: u32,
     last_accessed: u64,
+    /// Marks a track LRCLIB has no lyrics for
+    #[serde(default)]
+    missing: bool,
 }
 
 #[derive(Serialize, Deserialize)]
 struct CacheIndex {
+    #[serde(default)]
+    version: u32,
     entries: HashMap<String, CacheEntry>,
     total_requests: u64,
     cache_hits: u64,
+    #[serde(default)]
+    negative_hits: u64,
     last_cleanup: u64,
 }
 
+/// Index layout from before entries were split into files, kept to migrate old caches
+#[derive(Deserialize)]
+struct LegacyCacheIndex {
+    entries: HashMap<String, LegacyCacheEntry>,
+    total_requests: u64,
+    cache_hits: u64,
+    #[serde(default)]
+    negative_hits: u64,
+}
+
+#[derive(Deserialize)]
+struct LegacyCacheEntry {
+    lyrics: Option<LyricsResponse>,
+    cached_at: u64,
+    access_count: u32,
+    last_accessed: u64,
+}
+
+impl CacheEntry {
+    fn new(cached_at: u64, missing: bool) -> Self {
+        Self {
+            cached_at,
+            access_count: 1,
+            last_accessed: cached_at,
+            missing,
+        }
+    }
+
+    fn max_age_secs(&self, max_age_hours: u64, negative_max_age_hours: u64) -> u64 {
+        if self.missing {
+            negative_max_age_hours * 3600
+        } else {
+            max_age_hours * 3600
+        }
+    }
+}
+
+/// File-backed cache storing each entry in its own file, sharded by key prefix.
+///
+/// Lyrics are only read from disk on lookup; `cache_index.json` holds just the
+/// access metadata used for LRU eviction and expiry, and can be rebuilt from the
+/// entry files if it is lost.
 pub struct FileCache {
     cache_dir: PathBuf,
     index: CacheIndex,
     max_age_hours: u64,
+    negative_max_age_hours: u64,
     max_entries: usize,
 }
 
 impl FileCache {
     pub fn new(cache_dir: PathBuf) -> Result<Self> {
-        fs::create_dir_all(&cache_dir)?;
-        
-        let index_path = cache_dir.join("cache_index.json");
-        let index = if index_path.exists() {
-            let content = fs::read_to_string(&index_path)?;
-            serde_json::from_str(&content).unwrap_or_else(|_| CacheIndex::new())
-        } else {
-            CacheIndex::new()
-        };
+        fs::create_dir_all(cache_dir.join(ENTRIES_DIR))?;
 
-        Ok(Self {
+        let mut cache = Self {
             cache_dir,
-            index,
+            index: CacheIndex::new(),
             max_age_hours: 24 * 7, // 1 week
+            negative_max_age_hours: 24, // 1 day
             max_entries: 10000,
-        })
+        };
+        cache.load_index()?;
+
+        Ok(cache)
     }
 
+    fn load_index(&mut self) -> Result<()> {
+        let index_path = self.cache_dir.join(INDEX_FILE);
+        let content = match fs::read_to_string(&index_path) {
+            Ok(content) => content,
+            Err(_) => {
+                self.rebuild_index();
+                return Ok(());
+            }
+        };
+
+        let value: serde_json::Value = match serde_json::from_str(&content) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Cache index is corrupt, rebuilding from entry files: {}", e);
+                self.rebuild_index();
+                return Ok(());
+            }
+        };
+
+        if value.get("version").is_none() {
+            match serde_json::from_value::<LegacyCacheIndex>(value) {
+                Ok(legacy) => self.migrate_legacy_index(legacy)?,
+                Err(_) => self.rebuild_index(),
+            }
+            return Ok(());
+        }
+
+        match serde_json::from_value::<CacheIndex>(value) {
+            Ok(index) => self.index = index,
+            Err(e) => {
+                warn!("Cache index is unreadable, rebuilding from entry files: {}", e);
+                self.rebuild_index();
+            }
+        }
+        Ok(())
+    }
+
+    /// Move lyrics out of a single-file index into per-entry files
+    fn migrate_legacy_index(&mut self, legacy: LegacyCacheIndex) -> Result<()> {
+        info!("Migrating file cache with {} entries to per-entry storage", legacy.entries.len());
+
+        self.index.total_requests = legacy.total_requests;
+        self.index.cache_hits = legacy.cache_hits;
+        self.index.negative_hits = legacy.negative_hits;
+
+        for (key, legacy_entry) in legacy.entries {
+            if let Err(e) = self.write_entry_file(&key, legacy_entry.lyrics.as_ref()) {
+                warn!("Failed to migrate cache entry {}: {}", key, e);
+                continue;
+            }
+            self.index.entries.insert(key, CacheEntry {
+                cached_at: legacy_entry.cached_at,
+                access_count: legacy_entry.access_count,
+                last_accessed: legacy_entry.last_accessed,
+                missing: legacy_entry.lyrics.is_none(),
+            });
+        }
+
+        self.write_index()
+    }
+
+    /// Recreate the index from the entry files, using their modification time as the cache time
+    fn rebuild_index(&mut self) {
+        let shards = match fs::read_dir(self.cache_dir.join(ENTRIES_DIR)) {
+            Ok(shards) => shards,
+            Err(_) => return,
+        };
+
+        for file in shards.flatten().filter_map(|shard| fs::read_dir(shard.path()).ok()).flatten().flatten() {
+            let path = file.path();
+            let (key, missing) = match Self::parse_entry_file_name(&path) {
+                Some(parsed) => parsed,
+                None => continue,
+            };
+            let cached_at = file.metadata()
+                .and_then(|metadata| metadata.modified())
+                .ok()
+                .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                .map(|age| age.as_secs())
+                .unwrap_or_else(current_timestamp);
+
+            self.index.entries.insert(key, CacheEntry::new(cached_at, missing));
+        }
+
+        if !self.index.entries.is_empty() {
+            info!("Rebuilt file cache index with {} entries", self.index.entries.len());
+        }
+    }
 
     fn generate_key(&self, title: &str, artist: &str, album: &str, duration: f64) -> String {
         use std::hash::{Hash, Hasher};
@@ -80,14 +229,104 @@ impl FileCache {
         
         format!("{:x}", hasher.finish())
     }
+
+    /// Path of an entry file: `entries/<first two key chars>/<key>.json` (or `.missing`)
+    fn entry_path(&self, key: &str, missing: bool) -> PathBuf {
+        let shard = key.get(..2).unwrap_or("00");
+        let extension = if missing { "missing" } else { "json" };
+        self.cache_dir.join(ENTRIES_DIR).join(shard).join(format!("{}.{}", key, extension))
+    }
+
+    fn parse_entry_file_name(path: &Path) -> Option<(String, bool)> {
+        let key = path.file_stem()?.to_str()?.to_string();
+        match path.extension()?.to_str()? {
+            "json" => Some((key, false)),
+            "missing" => Some((key, true)),
+            _ => None,
+        }
+    }
+
+    /// Write an entry atomically and drop any file of the opposite kind for the same key
+    fn write_entry_file(&self, key: &str, lyrics: Option<&LyricsResponse>) -> Result<()> {
+        let path = self.entry_path(key, lyrics.is_none());
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let content = match lyrics {
+            Some(lyrics) => serde_json::to_vec(lyrics)?,
+            None => Vec::new(),
+        };
+        let tmp_path = unique_tmp_path(&path);
+        fs::write(&tmp_path, &content)?;
+        fs::rename(&tmp_path, &path)?;
+
+        let _ = fs::remove_file(self.entry_path(key, lyrics.is_some()));
+        Ok(())
+    }
+
+    fn read_entry_file(&self, key: &str) -> Result<LyricsResponse> {
+        let content = fs::read(self.entry_path(key, false))?;
+        Ok(serde_json::from_slice(&content)?)
+    }
+
+    fn remove_entry(&mut self, key: &str) {
+        if let Some(entry) = self.index.entries.remove(key) {
+            let _ = fs::remove_file(self.entry_path(key, entry.missing));
+        }
+    }
+
+    /// Pick up an entry file the index doesn't know about yet
+    fn discover_entry(&mut self, key: &str) -> bool {
+        for missing in [false, true] {
+            if let Ok(metadata) = fs::metadata(self.entry_path(key, missing)) {
+                let cached_at = metadata.modified()
+                    .ok()
+                    .and_then(|modified| modified.duration_since(UNIX_EPOCH).ok())
+                    .map(|age| age.as_secs())
+                    .unwrap_or_else(current_timestamp);
+                self.index.entries.insert(key.to_string(), CacheEntry::new(cached_at, missing));
+                return true;
+            }
+        }
+        false
+    }
+
+    async fn insert(&mut self, key: String, lyrics: Option<LyricsResponse>) -> Result<()> {
+        self.write_entry_file(&key, lyrics.as_ref())?;
+        self.index.entries.insert(key, CacheEntry::new(current_timestamp(), lyrics.is_none()));
+
+        // Cleanup if needed
+        if self.index.entries.len() > self.max_entries {
+            self.cleanup_old_entries().await?;
+        }
+
+        Ok(())
+    }
+
+    fn write_index(&self) -> Result<()> {
+        let index_path = self.cache_dir.join(INDEX_FILE);
+        let content = serde_json::to_string(&self.index)?;
+        // Ensure directory exists
+        if let Some(parent) = index_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        // Write atomically: write to temp then rename
+        let tmp_path = unique_tmp_path(&index_path);
+        fs::write(&tmp_path, &content)?;
+        std::fs::rename(&tmp_path, &index_path)?;
+        Ok(())
+    }
 }
 
 impl CacheIndex {
     fn new() -> Self {
         Self {
+            version: INDEX_VERSION,
             entries: HashMap::new(),
             total_requests: 0,
             cache_hits: 0,
+            negative_hits: 0,
             last_cleanup: current_timestamp(),
         }
     }
@@ -96,60 +335,93 @@ impl CacheIndex {
 #[derive(Debug, Clone)]
 pub struct CacheStats {
     pub total_entries: usize,
+    /// Entries remembering that a track has no lyrics
+    pub negative_entries: usize,
     pub total_requests: u64,
     pub cache_hits: u64,
+    /// Lookups answered by a negative entry
+    pub negative_hits: u64,
+    /// Share of requests answered by the cache, negative hits included
     pub hit_rate_percent: f64,
     pub last_cleanup: u64,
 }
 
+fn hit_rate(total_requests: u64, hits: u64) -> f64 {
+    if total_requests > 0 {
+        (hits as f64 / total_requests as f64) * 100.0
+    } else {
+        0.0
+    }
+}
+
 #[async_trait]
 impl LyricsCacheInterface for FileCache {
-    async fn get(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<LyricsResponse> {
+    async fn lookup(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<CachedLyrics> {
         let key = self.generate_key(title, artist, album, duration);
         self.index.total_requests += 1;
 
-        if let Some(entry) = self.index.entries.get_mut(&key) {
-            let now = current_timestamp();
-            
-            // Check if entry is expired
-            if now - entry.cached_at > self.max_age_hours * 3600 {
-                debug!("Cache entry expired for: {} - {}", artist, title);
-                self.index.entries.remove(&key);
-                return None;
+        if !self.index.entries.contains_key(&key) && !self.discover_entry(&key) {
+            debug!("Cache miss for: {} - {}", artist, title);
+            return None;
+        }
+
+        let now = current_timestamp();
+        let (expired, missing) = match self.index.entries.get(&key) {
+            Some(entry) => (
+                now.saturating_sub(entry.cached_at) > entry.max_age_secs(self.max_age_hours, self.negative_max_age_hours),
+                entry.missing,
+            ),
+            None => return None,
+        };
+
+        // Check if entry is expired
+        if expired {
+            debug!("Cache entry expired for: {} - {}", artist, title);
+            self.remove_entry(&key);
+            return None;
+        }
+
+        let cached = if missing {
+            self.index.negative_hits += 1;
+            debug!("Negative cache hit for: {} - {}", artist, title);
+            CachedLyrics::Missing
+        } else {
+            match self.read_entry_file(&key) {
+                Ok(lyrics) => {
+                    self.index.cache_hits += 1;
+                    debug!("Cache hit for: {} - {}", artist, title);
+                    CachedLyrics::Found(lyrics)
+                }
+                Err(e) => {
+                    warn!("Dropping unreadable cache entry for {} - {}: {}", artist, title, e);
+                    self.remove_entry(&key);
+                    return None;
+                }
             }
+        };
 
-            // Update access statistics
+        // Update access statistics
+        if let Some(entry) = self.index.entries.get_mut(&key) {
             entry.access_count += 1;
             entry.last_accessed = now;
-            self.index.cache_hits += 1;
-
-            debug!("Cache hit for: {} - {}", artist, title);
-            Some(entry.lyrics.clone())
-        } else {
-            debug!("Cache miss for: {} - {}", artist, title);
-            None
         }
+
+        Some(cached)
     }
 
     async fn put(&mut self, title: &str, artist: &str, album: &str, duration: f64, lyrics: LyricsResponse) -> Result<()> {
         let key = self.generate_key(title, artist, album, duration);
-        let now = current_timestamp();
+        self.insert(key, Some(lyrics)).await?;
 
-        let entry = CacheEntry {
-            lyrics,
-            cached_at: now,
-            access_count: 1,
-            last_accessed: now,
-        };
-
-        self.index.entries.insert(key, entry);
+        debug!("Cached lyrics for: {} - {}", artist, title);
+        Ok(())
+    }
 
-        // Cleanup if needed
-        if self.index.entries.len() > self.max_entries {
-            self.cleanup_old_entries().await?;
-        }
+    async fn put_missing(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Result<()> {
+        let key = self.generate_key(title, artist, album, duration);
+        self.insert(key, None).await?;
 
-        debug!("Cached lyrics for: {} - {}", artist, title);
+        debug!("Cached missing lyrics for: {} - {}", artist, title);
         Ok(())
     }
 
@@ -157,41 +429,44 @@ impl LyricsCacheInterface for FileCache {
         self.index.entries.clear();
         self.index.total_requests = 0;
         self.index.cache_hits = 0;
+        self.index.negative_hits = 0;
         self.index.last_cleanup = current_timestamp();
 
         // Remove cache files
         if self.cache_dir.exists() {
             fs::remove_dir_all(&self.cache_dir)?;
-            fs::create_dir_all(&self.cache_dir)?;
         }
+        fs::create_dir_all(self.cache_dir.join(ENTRIES_DIR))?;
 
         info!("File cache cleared");
         Ok(())
     }
 
     fn get_stats(&self) -> CacheStats {
-        let hit_rate = if self.index.total_requests > 0 {
-            (self.index.cache_hits as f64 / self.index.total_requests as f64) * 100.0
-        } else {
-            0.0
-        };
-
         CacheStats {
             total_entries: self.index.entries.len(),
+            negative_entries: self.index.entries.values().filter(|entry| entry.missing).count(),
             total_requests: self.index.total_requests,
             cache_hits: self.index.cache_hits,
-            hit_rate_percent: hit_rate,
+            negative_hits: self.index.negative_hits,
+            hit_rate_percent: hit_rate(self.index.total_requests, self.index.cache_hits + self.index.negative_hits),
             last_cleanup: self.index.last_cleanup,
         }
     }
 
     async fn cleanup_old_entries(&mut self) -> Result<()> {
         let now = current_timestamp();
-        let max_age = self.max_age_hours * 3600;
+        let (max_age_hours, negative_max_age_hours) = (self.max_age_hours, self.negative_max_age_hours);
 
         // Remove expired entries
         let before_count = self.index.entries.len();
-        self.index.entries.retain(|_, entry| now - entry.cached_at <= max_age);
+        let expired: Vec<String> = self.index.entries.iter()
+            .filter(|(_, entry)| now.saturating_sub(entry.cached_at) > entry.max_age_secs(max_age_hours, negative_max_age_hours))
+            .map(|(key, _)| key.clone())
+            .collect();
+        for key in expired {
+            self.remove_entry(&key);
+        }
         let after_cleanup_count = self.index.entries.len();
 
         // If still too many entries, remove least recently used
@@ -203,7 +478,7 @@ impl LyricsCacheInterface for FileCache {
             let keys_to_remove: Vec<String> = entries.iter().take(to_remove).map(|(key, _)| key.clone()).collect();
             
             for key in keys_to_remove {
-                self.index.entries.remove(&key);
+                self.remove_entry(&key);
             }
         }
 
@@ -217,17 +492,8 @@ impl LyricsCacheInterface for FileCache {
     }
 
     async fn save_index(&self) -> Result<()> {
-        let index_path = self.cache_dir.join("cache_index.json");
-        let content = serde_json::to_string_pretty(&self.index)?;
-        // Ensure directory exists
-        if let Some(parent) = index_path.parent() {
-            fs::create_dir_all(parent)?;
-        }
-        // Write atomically: write to temp then rename
-        let tmp_path = index_path.with_extension("json.tmp");
-        fs::write(&tmp_path, &content)?;
-        std::fs::rename(&tmp_path, &index_path)?;
-        Ok(())
+        // Entries are already on disk; only the access metadata needs saving
+        self.write_index()
     }
 }
 
@@ -235,7 +501,9 @@ impl LyricsCacheInterface for FileCache {
 pub struct RedisCache {
     client: RedisClient,
     key_prefix: String,
+    missing_key_prefix: String,
     ttl_seconds: u64,
+    negative_ttl_seconds: u64,
     stats: CacheStats,
 }
 
@@ -246,11 +514,15 @@ impl RedisCache {
         Ok(Self {
             client,
             key_prefix: "lrcget:lyrics:".to_string(),
+            missing_key_prefix: "lrcget:missing:".to_string(),
             ttl_seconds: 7 * 24 * 3600, // 7 days
+            negative_ttl_seconds: 24 * 3600, // 1 day
             stats: CacheStats {
                 total_entries: 0,
+                negative_entries: 0,
                 total_requests: 0,
                 cache_hits: 0,
+                negative_hits: 0,
                 hit_rate_percent: 0.0,
                 last_cleanup: current_timestamp(),
             },
@@ -258,6 +530,14 @@ impl RedisCache {
     }
 
     fn generate_key(&self, title: &str, artist: &str, album: &str, duration: f64) -> String {
+        format!("{}{}", self.key_prefix, Self::track_hash(title, artist, album, duration))
+    }
+
+    fn generate_missing_key(&self, title: &str, artist: &str, album: &str, duration: f64) -> String {
+        format!("{}{}", self.missing_key_prefix, Self::track_hash(title, artist, album, duration))
+    }
+
+    fn track_hash(title: &str, artist: &str, album: &str, duration: f64) -> String {
         use std::hash::{Hash, Hasher};
         use std::collections::hash_map::DefaultHasher;
 
@@ -266,26 +546,27 @@ impl RedisCache {
         artist.to_lowercase().hash(&mut hasher);
         album.to_lowercase().hash(&mut hasher);
         (duration as u64).hash(&mut hasher);
-        
-        format!("{}{:x}", self.key_prefix, hasher.finish())
+
+        format!("{:x}", hasher.finish())
     }
 }
 
 #[async_trait]
 impl LyricsCacheInterface for RedisCache {
-    async fn get(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<LyricsResponse> {
+    async fn lookup(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<CachedLyrics> {
         let key = self.generate_key(title, artist, album, duration);
+        let missing_key = self.generate_missing_key(title, artist, album, duration);
         self.stats.total_requests += 1;
 
         match self.client.get_async_connection().await {
             Ok(mut con) => {
-                match con.get::<_, Option<String>>(&key).await {
-                    Ok(Some(value)) => {
+                match con.get::<_, (Option<String>, Option<String>)>(&[&key, &missing_key]).await {
+                    Ok((Some(value), _)) => {
                         match serde_json::from_str::<LyricsResponse>(&value) {
                             Ok(lyrics) => {
                                 self.stats.cache_hits += 1;
                                 debug!("Redis cache hit for: {} - {}", artist, title);
-                                Some(lyrics)
+                                Some(CachedLyrics::Found(lyrics))
                             }
                             Err(e) => {
                                 warn!("Failed to deserialize cached lyrics: {}", e);
@@ -293,7 +574,12 @@ impl LyricsCacheInterface for RedisCache {
                             }
                         }
                     }
-                    Ok(None) => {
+                    Ok((None, Some(_))) => {
+                        self.stats.negative_hits += 1;
+                        debug!("Redis negative cache hit for: {} - {}", artist, title);
+                        Some(CachedLyrics::Missing)
+                    }
+                    Ok((None, None)) => {
                         debug!("Redis cache miss for: {} - {}", artist, title);
                         None
                     }
@@ -336,12 +622,34 @@ impl LyricsCacheInterface for RedisCache {
         }
     }
 
+    async fn put_missing(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Result<()> {
+        let key = self.generate_missing_key(title, artist, album, duration);
+
+        match self.client.get_async_connection().await {
+            Ok(mut con) => {
+                let _: () = con.set_ex(&key, 1, self.negative_ttl_seconds).await
+                    .map_err(|e| anyhow::anyhow!("Redis setex error: {}", e))?;
+
+                debug!("Cached missing lyrics in Redis for: {} - {}", artist, title);
+                Ok(())
+            }
+            Err(e) => {
+                warn!("Failed to connect to Redis for put: {}", e);
+                Err(anyhow::anyhow!("Redis connection error: {}", e))
+            }
+        }
+    }
+
     async fn clear(&mut self) -> Result<()> {
         match self.client.get_async_connection().await {
             Ok(mut con) => {
-                let pattern = format!("{}*", self.key_prefix);
-                let keys: Vec<String> = con.keys(&pattern).await
-                    .map_err(|e| anyhow::anyhow!("Redis keys error: {}", e))?;
+                let mut keys: Vec<String> = Vec::new();
+                for prefix in [&self.key_prefix, &self.missing_key_prefix] {
+                    let pattern = format!("{}*", prefix);
+                    let found: Vec<String> = con.keys(&pattern).await
+                        .map_err(|e| anyhow::anyhow!("Redis keys error: {}", e))?;
+                    keys.extend(found);
+                }
                 
                 if !keys.is_empty() {
                     let _: () = con.del(&keys).await
@@ -350,6 +658,7 @@ impl LyricsCacheInterface for RedisCache {
                 
                 self.stats.total_requests = 0;
                 self.stats.cache_hits = 0;
+                self.stats.negative_hits = 0;
                 self.stats.last_cleanup = current_timestamp();
                 
                 info!("Redis cache cleared, removed {} keys", keys.len());
@@ -364,11 +673,7 @@ impl LyricsCacheInterface for RedisCache {
 
     fn get_stats(&self) -> CacheStats {
         let mut stats = self.stats.clone();
-        stats.hit_rate_percent = if stats.total_requests > 0 {
-            (stats.cache_hits as f64 / stats.total_requests as f64) * 100.0
-        } else {
-            0.0
-        };
+        stats.hit_rate_percent = hit_rate(stats.total_requests, stats.cache_hits + stats.negative_hits);
         stats
     }
 
@@ -416,31 +721,44 @@ impl HybridCache {
             file_cache,
         })
     }
+
+    /// How long "no lyrics" entries are kept, in both caches
+    pub fn with_negative_ttl_hours(mut self, hours: u64) -> Self {
+        self.file_cache.negative_max_age_hours = hours;
+        if let Some(redis) = &mut self.redis_cache {
+            redis.negative_ttl_seconds = hours * 3600;
+        }
+        self
+    }
 }
 
 #[async_trait]
 impl LyricsCacheInterface for HybridCache {
-    async fn get(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<LyricsResponse> {
+    async fn lookup(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Option<CachedLyrics> {
         // Try Redis cache first
         if let Some(redis) = &mut self.redis_cache {
-            if let Some(lyrics) = redis.get(title, artist, album, duration).await {
+            if let Some(cached) = redis.lookup(title, artist, album, duration).await {
                 debug!("Hybrid cache: Redis hit for {} - {}", artist, title);
-                return Some(lyrics);
+                return Some(cached);
             }
         }
 
         // Fallback to file cache
-        if let Some(lyrics) = self.file_cache.get(title, artist, album, duration).await {
+        if let Some(cached) = self.file_cache.lookup(title, artist, album, duration).await {
             debug!("Hybrid cache: File hit for {} - {}", artist, title);
             
             // Update Redis cache if available
             if let Some(redis) = &mut self.redis_cache {
-                if let Err(e) = redis.put(title, artist, album, duration, lyrics.clone()).await {
+                let result = match &cached {
+                    CachedLyrics::Found(lyrics) => redis.put(title, artist, album, duration, lyrics.clone()).await,
+                    CachedLyrics::Missing => redis.put_missing(title, artist, album, duration).await,
+                };
+                if let Err(e) = result {
                     debug!("Failed to update Redis cache from file cache: {}", e);
                 }
             }
             
-            return Some(lyrics);
+            return Some(cached);
         }
 
         debug!("Hybrid cache: Miss for {} - {}", artist, title);
@@ -480,6 +798,27 @@ impl LyricsCacheInterface for HybridCache {
         }
     }
 
+    async fn put_missing(&mut self, title: &str, artist: &str, album: &str, duration: f64) -> Result<()> {
+        let mut redis_error = None;
+
+        if let Some(redis) = &mut self.redis_cache {
+            if let Err(e) = redis.put_missing(title, artist, album, duration).await {
+                redis_error = Some(e);
+            }
+        }
+
+        match (redis_error, self.file_cache.put_missing(title, artist, album, duration).await) {
+            (Some(redis_err), Err(file_err)) => {
+                Err(anyhow::anyhow!("Both caches failed - Redis: {}, File: {}", redis_err, file_err))
+            }
+            (Some(redis_err), Ok(())) => {
+                debug!("Redis cache put failed, but file cache succeeded: {}", redis_err);
+                Ok(())
+            }
+            (None, result) => result,
+        }
+    }
+
     async fn clear(&mut self) -> Result<()> {
         let mut errors = Vec::new();
 
@@ -508,19 +847,17 @@ impl LyricsCacheInterface for HybridCache {
             let redis_stats = redis.get_stats();
             
             // Combine stats from both caches
+            let total_requests = file_stats.total_requests + redis_stats.total_requests;
+            let cache_hits = file_stats.cache_hits + redis_stats.cache_hits;
+            let negative_hits = file_stats.negative_hits + redis_stats.negative_hits;
+
             CacheStats {
                 total_entries: file_stats.total_entries, // File cache tracks entries
-                total_requests: file_stats.total_requests + redis_stats.total_requests,
-                cache_hits: file_stats.cache_hits + redis_stats.cache_hits,
-                hit_rate_percent: {
-                    let total_reqs = file_stats.total_requests + redis_stats.total_requests;
-                    let total_hits = file_stats.cache_hits + redis_stats.cache_hits;
-                    if total_reqs > 0 {
-                        (total_hits as f64 / total_reqs as f64) * 100.0
-                    } else {
-                        0.0
-                    }
-                },
+                negative_entries: file_stats.negative_entries,
+                total_requests,
+                cache_hits,
+                negative_hits,
+                hit_rate_percent: hit_rate(total_requests, cache_hits + negative_hits),
                 last_cleanup: std::cmp::max(file_stats.last_cleanup, redis_stats.last_cleanup),
             }
         } else {
@@ -557,9 +894,190 @@ impl LyricsCacheInterface for HybridCache {
 // Type alias for backward compatibility
 pub type LyricsCache = HybridCache;
 
+/// Temp file next to `path` that no other writer, in this process or another, will use
+fn unique_tmp_path(path: &Path) -> PathBuf {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let mut file_name = path.file_name().unwrap_or_default().to_os_string();
+    file_name.push(format!(".{}-{}.tmp", std::process::id(), COUNTER.fetch_add(1, Ordering::Relaxed)));
+    path.with_file_name(file_name)
+}
+
 fn current_timestamp() -> u64 {
     SystemTime::now()
         .duration_since(UNIX_EPOCH)
         .unwrap_or(Duration::from_secs(0))
         .as_secs()
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    fn lyrics(id: i64) -> LyricsResponse {
+        LyricsResponse {
+            id: Some(id),
+            plain_lyrics: Some("Words".to_string()),
+            synced_lyrics: None,
+            instrumental: false,
+            duration: Some(200.0),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_entries_are_sharded_files_read_on_lookup() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+        cache.put("Song", "Artist", "Album", 200.0, lyrics(7)).await.unwrap();
+        cache.put_missing("Other", "Artist", "Album", 200.0).await.unwrap();
+        cache.save_index().await.unwrap();
+
+        let key = cache.generate_key("Song", "Artist", "Album", 200.0);
+        let entry_path = dir.join(ENTRIES_DIR).join(&key[..2]).join(format!("{}.json", key));
+        assert!(entry_path.exists());
+        let missing_key = cache.generate_key("Other", "Artist", "Album", 200.0);
+        assert!(cache.entry_path(&missing_key, true).exists());
+
+        // The index only holds access metadata; lyrics stay in the entry files
+        let index = fs::read_to_string(dir.join(INDEX_FILE)).unwrap();
+        assert!(!index.contains("Words"));
+
+        let mut reopened = FileCache::new(dir.clone()).unwrap();
+        match reopened.lookup("Song", "Artist", "Album", 200.0).await {
+            Some(CachedLyrics::Found(found)) => assert_eq!(found.id, Some(7)),
+            other => panic!("expected cached lyrics, got {:?}", other),
+        }
+
+        // Entries written by another instance are picked up without an index update
+        cache.put("Third", "Artist", "Album", 200.0, lyrics(9)).await.unwrap();
+        assert!(matches!(reopened.lookup("Third", "Artist", "Album", 200.0).await, Some(CachedLyrics::Found(_))));
+    }
+
+    #[test]
+    fn test_unique_tmp_paths() {
+        let path = Path::new("/cache/entries/ab/abcd.json");
+        let (first, second) = (unique_tmp_path(path), unique_tmp_path(path));
+        assert_ne!(first, second);
+        assert_eq!(first.parent(), path.parent());
+        assert_eq!(FileCache::parse_entry_file_name(&first), None);
+    }
+
+    #[tokio::test]
+    async fn test_migrate_legacy_index() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let empty = FileCache::new(dir.clone()).unwrap();
+        let key = empty.generate_key("Song", "Artist", "Album", 200.0);
+        let missing_key = empty.generate_key("Gone", "Artist", "Album", 200.0);
+        let now = current_timestamp();
+        let legacy = serde_json::json!({
+            "entries": {
+                key.clone(): { "lyrics": lyrics(3), "cached_at": now, "access_count": 4, "last_accessed": now },
+                missing_key.clone(): { "lyrics": null, "cached_at": now, "access_count": 1, "last_accessed": now },
+            },
+            "total_requests": 10,
+            "cache_hits": 6,
+            "last_cleanup": now,
+        });
+        fs::write(dir.join(INDEX_FILE), legacy.to_string()).unwrap();
+
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+        assert_eq!(cache.index.version, INDEX_VERSION);
+        assert_eq!((cache.index.total_requests, cache.index.cache_hits), (10, 6));
+        assert_eq!(cache.index.entries[&key].access_count, 4);
+        assert!(cache.entry_path(&key, false).exists());
+        assert!(cache.entry_path(&missing_key, true).exists());
+
+        assert!(matches!(cache.lookup("Song", "Artist", "Album", 200.0).await, Some(CachedLyrics::Found(_))));
+        assert!(matches!(cache.lookup("Gone", "Artist", "Album", 200.0).await, Some(CachedLyrics::Missing)));
+
+        let index: serde_json::Value = serde_json::from_str(&fs::read_to_string(dir.join(INDEX_FILE)).unwrap()).unwrap();
+        assert_eq!(index["version"], INDEX_VERSION);
+    }
+
+    #[tokio::test]
+    async fn test_rebuild_index_from_entry_files() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+        cache.put("Song", "Artist", "Album", 200.0, lyrics(1)).await.unwrap();
+        cache.put_missing("Gone", "Artist", "Album", 200.0).await.unwrap();
+        cache.save_index().await.unwrap();
+
+        fs::write(dir.join(INDEX_FILE), "{ not json").unwrap();
+        let rebuilt = FileCache::new(dir.clone()).unwrap();
+        assert_eq!(rebuilt.index.entries.len(), 2);
+        assert_eq!(rebuilt.get_stats().negative_entries, 1);
+
+        fs::remove_file(dir.join(INDEX_FILE)).unwrap();
+        assert_eq!(FileCache::new(dir.clone()).unwrap().index.entries.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cleanup_expires_old_entries_and_evicts_least_recently_used() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+        for (i, title) in ["Old", "A", "B", "C"].iter().enumerate() {
+            cache.put(title, "Artist", "Album", 200.0, lyrics(i as i64)).await.unwrap();
+        }
+
+        let now = current_timestamp();
+        let key = |title: &str| cache.generate_key(title, "Artist", "Album", 200.0);
+        let (old, a, b, c) = (key("Old"), key("A"), key("B"), key("C"));
+        cache.index.entries.get_mut(&old).unwrap().cached_at = now - (cache.max_age_hours + 1) * 3600;
+        cache.index.entries.get_mut(&a).unwrap().last_accessed = now - 30;
+        cache.index.entries.get_mut(&b).unwrap().last_accessed = now - 10;
+        cache.index.entries.get_mut(&c).unwrap().last_accessed = now - 20;
+        cache.max_entries = 2;
+
+        cache.cleanup_old_entries().await.unwrap();
+
+        let mut kept: Vec<&String> = cache.index.entries.keys().collect();
+        kept.sort();
+        let mut expected = vec![&b, &c];
+        expected.sort();
+        assert_eq!(kept, expected);
+        assert!(!cache.entry_path(&old, false).exists());
+        assert!(!cache.entry_path(&a, false).exists());
+    }
+
+    #[tokio::test]
+    async fn test_put_missing_is_a_negative_hit() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+
+        assert!(cache.lookup("Song", "Artist", "Album", 200.0).await.is_none());
+        cache.put_missing("Song", "Artist", "Album", 200.0).await.unwrap();
+        assert!(matches!(cache.lookup("Song", "Artist", "Album", 200.0).await, Some(CachedLyrics::Missing)));
+
+        let stats = cache.get_stats();
+        assert_eq!((stats.total_entries, stats.negative_entries), (1, 1));
+        assert_eq!((stats.total_requests, stats.cache_hits, stats.negative_hits), (2, 0, 1));
+        assert_eq!(stats.hit_rate_percent, 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_negative_entries_expire_sooner() {
+        let test_dir = TestDir::new("cache");
+        let dir = test_dir.path().to_path_buf();
+        let mut cache = FileCache::new(dir.clone()).unwrap();
+        cache.negative_max_age_hours = 2;
+
+        cache.put_missing("Gone", "Artist", "Album", 200.0).await.unwrap();
+        cache.put("Kept", "Artist", "Album", 200.0, lyrics(1)).await.unwrap();
+
+        // Both three hours old: past the negative TTL, well within the regular one
+        let three_hours_ago = current_timestamp() - 3 * 3600;
+        for entry in cache.index.entries.values_mut() {
+            entry.cached_at = three_hours_ago;
+        }
+
+        assert!(cache.lookup("Gone", "Artist", "Album", 200.0).await.is_none());
+        assert!(matches!(cache.lookup("Kept", "Artist", "Album", 200.0).await, Some(CachedLyrics::Found(_))));
+        assert_eq!(cache.get_stats().negative_entries, 0);
+    }
+}
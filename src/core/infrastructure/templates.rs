@@ -42,6 +42,7 @@ pub struct TemplateStats {
     pub tracks_with_synced_lyrics: usize,
     pub tracks_with_plain_lyrics: usize,
     pub tracks_with_any_lyrics: usize,
+    pub tracks_instrumental: usize,
     pub tracks_missing_lyrics: usize,
     pub coverage_percentage: f64,
     pub unique_artists: usize,
@@ -289,6 +290,7 @@ LRCGET Version:   {{version}}
 Synced Lyrics:    {{stats.tracks_with_synced_lyrics}} tracks
 Plain Lyrics:     {{stats.tracks_with_plain_lyrics}} tracks
 Any Lyrics:       {{stats.tracks_with_any_lyrics}} tracks
+Instrumental:     {{stats.tracks_instrumental}} tracks
 Missing Lyrics:   {{stats.tracks_missing_lyrics}} tracks
 Coverage Rate:    {{format_percentage stats.coverage_percentage 1}}
 
@@ -317,7 +319,7 @@ Coverage Rate:    {{format_percentage stats.coverage_percentage 1}}
    Album: {{album_name}}
    Duration: {{format_duration duration}}
    Lyrics: {{#if lrc_lyrics}}🎼{{else}}  {{/if}} {{#if txt_lyrics}}📝{{else}}  {{/if}}
-   {{#unless lrc_lyrics}}{{#unless txt_lyrics}}❌ Missing{{/unless}}{{/unless}}
+   {{#if instrumental}}🎻 Instrumental{{else}}{{#unless lrc_lyrics}}{{#unless txt_lyrics}}❌ Missing{{/unless}}{{/unless}}{{/if}}
 
 {{/each}}
 
@@ -368,10 +370,11 @@ fn calculate_stats(tracks: &[DatabaseTrack]) -> TemplateStats {
     let any_lyrics_count = tracks.iter()
         .filter(|t| t.lrc_lyrics.is_some() || t.txt_lyrics.is_some())
         .count();
-    let missing_count = total - any_lyrics_count;
+    let instrumental_count = tracks.iter().filter(|t| t.instrumental).count();
+    let missing_count = tracks.iter().filter(|t| t.is_missing_lyrics()).count();
     
     let coverage = if total > 0 {
-        (any_lyrics_count as f64 / total as f64) * 100.0
+        ((total - missing_count) as f64 / total as f64) * 100.0
     } else {
         0.0
     };
@@ -391,6 +394,7 @@ fn calculate_stats(tracks: &[DatabaseTrack]) -> TemplateStats {
         tracks_with_synced_lyrics: synced_count,
         tracks_with_plain_lyrics: plain_count,
         tracks_with_any_lyrics: any_lyrics_count,
+        tracks_instrumental: instrumental_count,
         tracks_missing_lyrics: missing_count,
         coverage_percentage: coverage,
         unique_artists,
@@ -0,0 +1,42 @@
+use anyhow::{Context, Result};
+use data_encoding::HEXUPPER_PERMISSIVE;
+use ring::digest::{digest, SHA256};
+
+/// Find the nonce for an LRCLIB publish challenge: the smallest number whose
+/// `SHA-256(prefix + nonce)` is at or below `target` when both are read as big-endian numbers.
+///
+/// This is deliberately slow (seconds on a typical machine); call it from `spawn_blocking`.
+pub fn solve_challenge(prefix: &str, target_hex: &str) -> Result<String> {
+    let target = HEXUPPER_PERMISSIVE
+        .decode(target_hex.trim().as_bytes())
+        .with_context(|| format!("Invalid challenge target '{}'", target_hex))?;
+    if target.len() != SHA256.output_len() {
+        anyhow::bail!("Challenge target has {} bytes, expected {}", target.len(), SHA256.output_len());
+    }
+
+    let mut nonce: u64 = 0;
+    loop {
+        let input = format!("{}{}", prefix, nonce);
+        // Equal-length byte slices compare as big-endian numbers
+        if digest(&SHA256, input.as_bytes()).as_ref() <= target.as_slice() {
+            return Ok(nonce.to_string());
+        }
+        nonce += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve_challenge_meets_target() {
+        // One leading zero byte: about 256 attempts
+        let target = format!("00{}", "FF".repeat(31));
+        let nonce = solve_challenge("prefix", &target).unwrap();
+
+        let hash = digest(&SHA256, format!("prefix{}", nonce).as_bytes());
+        assert_eq!(hash.as_ref()[0], 0);
+        assert!(solve_challenge("prefix", "ABCD").is_err());
+    }
+}
@@ -0,0 +1,206 @@
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+use tracing::debug;
+
+/// A response this much slower than the fastest recent one counts as congestion
+const LATENCY_TOLERANCE: f64 = 2.0;
+
+/// Cut applied when responses slow down
+const LATENCY_DECREASE: f64 = 0.75;
+
+/// Cut applied on 429, 5xx and network errors
+const OVERLOAD_DECREASE: f64 = 0.5;
+
+/// Several in-flight requests report the same congestion; only the first of them cuts
+const DECREASE_COOLDOWN: Duration = Duration::from_secs(2);
+
+/// How fast the latency baseline forgets an unusually quick response, per sample
+const BASELINE_DRIFT: f64 = 1.01;
+
+/// What an LRCLIB request told us about the server
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RequestFeedback {
+    /// Answered (including 404) after this long
+    Completed(Duration),
+    /// 429, 5xx or a network error
+    Overloaded,
+}
+
+struct AimdState {
+    limit: f64,
+    min: usize,
+    max: usize,
+    active: usize,
+    /// Fastest recent response, drifting upwards so the network can change under us
+    baseline: Option<Duration>,
+    last_decrease: Option<Instant>,
+}
+
+impl AimdState {
+    fn effective(&self) -> usize {
+        (self.limit.floor() as usize).clamp(self.min, self.max)
+    }
+
+    fn record(&mut self, feedback: RequestFeedback, now: Instant) {
+        match feedback {
+            RequestFeedback::Overloaded => self.decrease(OVERLOAD_DECREASE, now),
+            RequestFeedback::Completed(latency) => {
+                let baseline = match self.baseline {
+                    Some(baseline) => latency.min(baseline.mul_f64(BASELINE_DRIFT)),
+                    None => latency,
+                };
+                self.baseline = Some(baseline);
+
+                if latency.as_secs_f64() > baseline.as_secs_f64() * LATENCY_TOLERANCE {
+                    self.decrease(LATENCY_DECREASE, now);
+                } else if self.active >= self.effective() {
+                    // Grow by about one per round of requests, and only while the limit is in use
+                    self.limit = (self.limit + 1.0 / self.limit).min(self.max as f64);
+                }
+            }
+        }
+    }
+
+    fn decrease(&mut self, factor: f64, now: Instant) {
+        if self.last_decrease.is_some_and(|last| now.duration_since(last) < DECREASE_COOLDOWN) {
+            return;
+        }
+        self.limit = (self.limit * factor).max(self.min as f64);
+        self.last_decrease = Some(now);
+    }
+}
+
+/// Additive-increase/multiplicative-decrease limit on parallel downloads, fed by the
+/// latency and errors of LRCLIB requests (see `LrclibClient::with_concurrency`)
+pub struct AdaptiveConcurrency {
+    state: Mutex<AimdState>,
+    notify: Notify,
+}
+
+/// A running download; frees its slot when dropped
+pub struct ConcurrencyPermit<'a> {
+    controller: &'a AdaptiveConcurrency,
+}
+
+impl AdaptiveConcurrency {
+    /// Start at `initial` parallel downloads, adjusted between `min` and `max`
+    pub fn new(initial: usize, min: usize, max: usize) -> Self {
+        let min = min.max(1);
+        let max = max.max(min);
+        Self {
+            state: Mutex::new(AimdState {
+                limit: initial.clamp(min, max) as f64,
+                min,
+                max,
+                active: 0,
+                baseline: None,
+                last_decrease: None,
+            }),
+            notify: Notify::new(),
+        }
+    }
+
+    /// Number of downloads currently allowed to run at once
+    pub fn limit(&self) -> usize {
+        self.lock().effective()
+    }
+
+    /// Wait until the current limit leaves room for another download
+    pub async fn acquire(&self) -> ConcurrencyPermit<'_> {
+        loop {
+            // Register before checking so a release in between is not missed
+            let mut notified = std::pin::pin!(self.notify.notified());
+            notified.as_mut().enable();
+
+            {
+                let mut state = self.lock();
+                if state.active < state.effective() {
+                    state.active += 1;
+                    return ConcurrencyPermit { controller: self };
+                }
+            }
+
+            notified.await;
+        }
+    }
+
+    /// Adjust the limit from the outcome of one request
+    pub fn record(&self, feedback: RequestFeedback) {
+        let (before, after) = {
+            let mut state = self.lock();
+            let before = state.effective();
+            state.record(feedback, Instant::now());
+            (before, state.effective())
+        };
+
+        if after != before {
+            debug!("Parallel downloads adjusted from {} to {}", before, after);
+            if after > before {
+                self.notify.notify_waiters();
+            }
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, AimdState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Drop for ConcurrencyPermit<'_> {
+    fn drop(&mut self) {
+        self.controller.lock().active -= 1;
+        self.controller.notify.notify_waiters();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(limit: f64, active: usize) -> AimdState {
+        AimdState {
+            limit,
+            min: 2,
+            max: 8,
+            active,
+            baseline: None,
+            last_decrease: None,
+        }
+    }
+
+    #[test]
+    fn test_aimd_adjusts_within_bounds() {
+        let start = Instant::now();
+        let fast = RequestFeedback::Completed(Duration::from_millis(100));
+
+        // Grows by one per round of requests while every slot is busy, up to max
+        let mut aimd = state(4.0, 8);
+        for _ in 0..5 {
+            aimd.record(fast, start);
+        }
+        assert_eq!(aimd.effective(), 5);
+        for _ in 0..100 {
+            aimd.record(fast, start);
+        }
+        assert_eq!(aimd.effective(), 8);
+
+        // Idle slots are no evidence the server can take more
+        let mut idle = state(4.0, 1);
+        idle.record(fast, start);
+        assert_eq!(idle.limit, 4.0);
+
+        // Overload halves, but only once per cooldown
+        aimd.record(RequestFeedback::Overloaded, start);
+        assert_eq!(aimd.effective(), 4);
+        aimd.record(RequestFeedback::Overloaded, start + Duration::from_secs(1));
+        assert_eq!(aimd.effective(), 4);
+
+        // Slow responses cut by a quarter, never below min
+        let later = start + Duration::from_secs(5);
+        aimd.record(RequestFeedback::Completed(Duration::from_millis(500)), later);
+        assert_eq!(aimd.effective(), 3);
+        aimd.record(RequestFeedback::Overloaded, later + Duration::from_secs(5));
+        assert_eq!(aimd.effective(), 2);
+    }
+}
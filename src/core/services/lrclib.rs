@@ -3,14 +3,19 @@ use reqwest;
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::time::Duration;
-use tokio::sync::RwLock;
+use std::collections::HashSet;
+use tokio::sync::{OnceCell, RwLock};
 use tracing::{debug, info, warn};
 use fuzzy_matcher::FuzzyMatcher;
 use fuzzy_matcher::skim::SkimMatcherV2;
 
-use crate::core::data::database::DatabaseTrack;
-use crate::core::infrastructure::cache::{LyricsCache, LyricsCacheInterface};
+use crate::core::data::database::{DatabaseTrack, DownloadOutcome};
+use crate::core::infrastructure::cache::{CachedLyrics, LyricsCache, LyricsCacheInterface};
+use crate::core::files::duration_check::{check_duration, DurationCheck, DurationMismatchAction, DurationPolicy};
+use crate::core::files::lyrics::{LyricsManager, LyricsStoragePolicy};
 use crate::core::data::lrclib_db::LrclibDatabase;
+use crate::core::services::concurrency::{AdaptiveConcurrency, RequestFeedback};
+use crate::core::services::rate_limit::{parse_retry_after, RateLimiter};
 
 #[derive(Debug, Clone)]
 pub struct LyricsDownloadResult {
@@ -18,6 +23,46 @@ pub struct LyricsDownloadResult {
     pub instrumental: bool,
     pub synced_lyrics: bool,
     pub plain_lyrics: bool,
+    /// Where the lyrics came from, if found
+    pub source: Option<SearchResultSource>,
+    /// LRCLIB record id of the lyrics, if known
+    pub lrclib_id: Option<i64>,
+    /// How well the lyrics fit the track length, when it could be checked
+    pub confidence: Option<f64>,
+    /// Lyrics were found but not saved because their length does not fit the track
+    pub rejected: bool,
+}
+
+impl LyricsDownloadResult {
+    pub fn not_found() -> Self {
+        Self {
+            found: false,
+            instrumental: false,
+            synced_lyrics: false,
+            plain_lyrics: false,
+            source: None,
+            lrclib_id: None,
+            confidence: None,
+            rejected: false,
+        }
+    }
+
+    /// Outcome to record in the library's download history
+    pub fn outcome(&self) -> DownloadOutcome {
+        if self.rejected {
+            DownloadOutcome::Mismatch
+        } else if !self.found {
+            DownloadOutcome::NotFound
+        } else if self.instrumental {
+            DownloadOutcome::Instrumental
+        } else if self.synced_lyrics {
+            DownloadOutcome::Synced
+        } else if self.plain_lyrics {
+            DownloadOutcome::Plain
+        } else {
+            DownloadOutcome::NotFound
+        }
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -59,14 +104,21 @@ fn default_search_result_source() -> SearchResultSource {
 #[derive(Deserialize, Serialize, Debug, Clone)]
 #[serde(rename_all = "camelCase")]
 pub struct LyricsResponse {
+    #[serde(default)]
+    pub id: Option<i64>,
     pub plain_lyrics: Option<String>,
     pub synced_lyrics: Option<String>,
     pub instrumental: bool,
+    /// Track length LRCLIB has for these lyrics, in seconds
+    #[serde(default)]
+    pub duration: Option<f64>,
 }
 
 impl From<SearchResult> for LyricsResponse {
     fn from(search_result: SearchResult) -> Self {
         LyricsResponse {
+            id: search_result.id,
+            duration: search_result.duration,
             plain_lyrics: search_result.plain_lyrics,
             synced_lyrics: search_result.synced_lyrics,
             instrumental: search_result.instrumental,
@@ -74,11 +126,40 @@ impl From<SearchResult> for LyricsResponse {
     }
 }
 
+/// Proof-of-work challenge handed out by `/api/request-challenge`
+#[derive(Deserialize, Debug, Clone)]
+pub struct PublishChallenge {
+    pub prefix: String,
+    /// Hex-encoded SHA-256 value the solution's hash must not exceed
+    pub target: String,
+}
+
+/// Body of `/api/publish`. Empty plain and synced lyrics mark the track as instrumental.
+#[derive(Serialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishRequest {
+    pub track_name: String,
+    pub artist_name: String,
+    pub album_name: String,
+    pub duration: f64,
+    pub plain_lyrics: String,
+    pub synced_lyrics: String,
+}
+
+/// Error body LRCLIB returns with a failed request
+#[derive(Deserialize, Debug)]
+struct ApiError {
+    message: Option<String>,
+}
+
 #[derive(Clone)]
 pub struct LrclibClient {
     client: reqwest::Client,
     base_url: String,
     local_db_path: Option<std::path::PathBuf>,
+    concurrency: Option<Arc<AdaptiveConcurrency>>,
+    /// Flagged LRCLIB ids, read from the local database once per client
+    flagged_ids: Arc<OnceCell<HashSet<i64>>>,
 }
 
 impl LrclibClient {
@@ -96,6 +177,8 @@ impl LrclibClient {
             client,
             base_url: base_url.trim_end_matches('/').to_string(),
             local_db_path: None,
+            concurrency: None,
+            flagged_ids: Arc::new(OnceCell::new()),
         }
     }
 
@@ -105,6 +188,12 @@ impl LrclibClient {
         client
     }
 
+    /// Report the latency and errors of API requests to an adaptive download limit
+    pub fn with_concurrency(mut self, concurrency: Arc<AdaptiveConcurrency>) -> Self {
+        self.concurrency = Some(concurrency);
+        self
+    }
+
     pub async fn search(
         &self,
         title: &str,
@@ -148,64 +237,35 @@ impl LrclibClient {
 
         info!("Searching LRCLIB API with params: {:?}", params);
 
-        // Basic retry with exponential backoff for transient errors
-        let mut attempt = 0u32;
-        let max_attempts = 3u32;
-        loop {
-            attempt += 1;
-            let resp_result = self.client
-                .get(&url)
-                .query(&params)
-                .send()
-                .await;
-
-            match resp_result {
-                Ok(response) => {
-                    if response.status().is_success() {
-                        let mut results: Vec<SearchResult> = response.json().await?;
-
-                        // Mark all results as coming from API
-                        for result in &mut results {
-                            result.source = SearchResultSource::Api;
-                        }
+        let response = self.send_get(&url, &params)
+            .await
+            .map_err(|e| anyhow::anyhow!("Search request error: {}", e))?;
 
-                        // 3) If we have a local DB configured, update it with API results
-                        if !results.is_empty() {
-                            if let Some(ref db_path) = self.local_db_path {
-                                if db_path.exists() {
-                                    if let Err(e) = self.update_local_db_with_search_results(&results).await {
-                                        warn!("Failed to update local database from search results: {}", e);
-                                    }
-                                }
-                            }
-                        }
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("Search failed: {}", status);
+        }
 
-                        return Ok(results);
-                    }
+        let mut results: Vec<SearchResult> = response.json().await?;
 
-                    let status = response.status();
-                    // Retry on 429 Too Many Requests and 5xx server errors
-                    if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
-                        if attempt < max_attempts {
-                            let backoff = 2u64.pow(attempt - 1) * 300; // 300ms, 600ms
-                            tokio::time::sleep(Duration::from_millis(backoff)).await;
-                            continue;
-                        }
-                    }
+        // Mark all results as coming from API
+        for result in &mut results {
+            result.source = SearchResultSource::Api;
+        }
+        self.drop_flagged(&mut results).await;
 
-                    anyhow::bail!("Search failed: {}", status);
-                }
-                Err(e) => {
-                    // Retry on network errors
-                    if attempt < max_attempts {
-                        let backoff = 2u64.pow(attempt - 1) * 300; // 300ms, 600ms
-                        tokio::time::sleep(Duration::from_millis(backoff)).await;
-                        continue;
+        // 3) If we have a local DB configured, update it with API results
+        if !results.is_empty() {
+            if let Some(ref db_path) = self.local_db_path {
+                if db_path.exists() {
+                    if let Err(e) = self.update_local_db_with_search_results(&results).await {
+                        warn!("Failed to update local database from search results: {}", e);
                     }
-                    return Err(anyhow::anyhow!("Search request error: {}", e));
                 }
             }
         }
+
+        Ok(results)
     }
 
     pub async fn get_lyrics(
@@ -214,6 +274,29 @@ impl LrclibClient {
         artist: &str,
         album: &str,
         duration: f64,
+    ) -> Result<Option<SearchResult>> {
+        self.lookup_lyrics("/api/get", title, artist, album, duration).await
+    }
+
+    /// Like `get_lyrics`, but through `/api/get-cached`, which only answers from LRCLIB's
+    /// own records and never triggers scraping of external sources
+    pub async fn get_lyrics_cached(
+        &self,
+        title: &str,
+        artist: &str,
+        album: &str,
+        duration: f64,
+    ) -> Result<Option<SearchResult>> {
+        self.lookup_lyrics("/api/get-cached", title, artist, album, duration).await
+    }
+
+    async fn lookup_lyrics(
+        &self,
+        endpoint: &str,
+        title: &str,
+        artist: &str,
+        album: &str,
+        duration: f64,
     ) -> Result<Option<SearchResult>> {
         // Try local database first if available
         if let Some(ref db_path) = self.local_db_path {
@@ -236,7 +319,7 @@ impl LrclibClient {
 
         // Fallback to remote API
         debug!("Getting lyrics from LRCLIB API for: {} - {}", artist, title);
-        let url = format!("{}/api/get", self.base_url);
+        let url = format!("{}{}", self.base_url, endpoint);
         
         let duration_str = duration.round().to_string();
         let params = vec![
@@ -246,58 +329,175 @@ impl LrclibClient {
             ("duration", &duration_str),
         ];
 
-        // Basic retry with exponential backoff for transient errors
-        let mut attempt = 0u32;
-        let max_attempts = 3u32;
+        let Some(lyrics) = self.get_record(&url, &params).await? else {
+            info!("No lyrics found for: {} - {}", artist, title);
+            return Ok(None);
+        };
+
+        if self.is_flagged(lyrics.id).await {
+            info!("Skipping flagged lyrics for: {} - {}", artist, title);
+            return Ok(None);
+        }
+
+        // Update local database if we have one and got results from API
+        if let Some(ref _db_path) = self.local_db_path {
+            if let Err(e) = self.update_local_db(&lyrics, title, artist, album, duration).await {
+                warn!("Failed to update local database: {}", e);
+            }
+        }
+
+        Ok(Some(lyrics))
+    }
+
+    /// Fetch one LRCLIB record by id. Always asks the API: ids in the local database only
+    /// match LRCLIB's for records that came from a dump.
+    pub async fn get_lyrics_by_id(&self, id: i64) -> Result<Option<SearchResult>> {
+        debug!("Getting LRCLIB record {}", id);
+        let url = format!("{}/api/get/{}", self.base_url, id);
+        self.get_record(&url, &[]).await
+    }
+
+    /// GET a single record, retrying transient errors. A 404 is `None`.
+    async fn get_record(&self, url: &str, params: &[(&str, &str)]) -> Result<Option<SearchResult>> {
+        let response = self.send_get(url, params)
+            .await
+            .map_err(|e| anyhow::anyhow!("Get lyrics request error: {}", e))?;
+
+        match response.status() {
+            reqwest::StatusCode::OK => Ok(Some(response.json().await?)),
+            reqwest::StatusCode::NOT_FOUND => Ok(None),
+            status => anyhow::bail!("Failed to get lyrics: {}", status),
+        }
+    }
+
+    /// Send a GET through the process-wide rate limiter, retrying 429, 5xx and network
+    /// errors with exponential backoff. A `Retry-After` on 429/503 pauses every request,
+    /// not just this one. The last response is returned whatever its status.
+    async fn send_get(&self, url: &str, params: &[(&str, &str)]) -> reqwest::Result<reqwest::Response> {
+        let limiter = RateLimiter::global();
+        let max_retries = limiter.max_retries();
+        let mut retries = 0u32;
+
         loop {
-            attempt += 1;
-            let resp_result = self.client
-                .get(&url)
-                .query(&params)
-                .send()
-                .await;
-
-            match resp_result {
+            limiter.acquire().await;
+            let backoff = Duration::from_millis(300 * 2u64.pow(retries.min(10))); // 300ms, 600ms, 1.2s...
+
+            let started = std::time::Instant::now();
+            let result = self.client.get(url).query(params).send().await;
+            if let Some(concurrency) = &self.concurrency {
+                concurrency.record(match &result {
+                    Ok(response) if !is_overload_status(response.status()) => RequestFeedback::Completed(started.elapsed()),
+                    _ => RequestFeedback::Overloaded,
+                });
+            }
+
+            match result {
                 Ok(response) => {
-                    match response.status() {
-                        reqwest::StatusCode::OK => {
-                            let lyrics: SearchResult = response.json().await?;
-                            
-                            // Update local database if we have one and got results from API
-                            if let Some(ref _db_path) = self.local_db_path {
-                                if let Err(e) = self.update_local_db(&lyrics, title, artist, album, duration).await {
-                                    warn!("Failed to update local database: {}", e);
-                                }
-                            }
-                            
-                            return Ok(Some(lyrics));
-                        },
-                        reqwest::StatusCode::NOT_FOUND => {
-                            info!("No lyrics found for: {} - {}", artist, title);
-                            return Ok(None);
-                        },
-                        status => {
-                            if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error() {
-                                if attempt < max_attempts {
-                                    let backoff = 2u64.pow(attempt - 1) * 300; // 300ms, 600ms
-                                    tokio::time::sleep(Duration::from_millis(backoff)).await;
-                                    continue;
-                                }
-                            }
-                            anyhow::bail!("Failed to get lyrics: {}", status);
-                        }
+                    let status = response.status();
+                    if !is_overload_status(status) || retries >= max_retries {
+                        return Ok(response);
+                    }
+
+                    if status == reqwest::StatusCode::TOO_MANY_REQUESTS || status == reqwest::StatusCode::SERVICE_UNAVAILABLE {
+                        let retry_after = response
+                            .headers()
+                            .get(reqwest::header::RETRY_AFTER)
+                            .and_then(|value| value.to_str().ok())
+                            .and_then(|value| parse_retry_after(value, chrono::Utc::now()));
+                        // Without a hint, back off everyone anyway: the whole process is too fast
+                        limiter.pause_for(retry_after.unwrap_or(backoff));
+                    } else {
+                        tokio::time::sleep(backoff).await;
                     }
                 }
                 Err(e) => {
-                    if attempt < max_attempts {
-                        let backoff = 2u64.pow(attempt - 1) * 300; // 300ms, 600ms
-                        tokio::time::sleep(Duration::from_millis(backoff)).await;
-                        continue;
+                    if retries >= max_retries {
+                        return Err(e);
                     }
-                    return Err(anyhow::anyhow!("Get lyrics request error: {}", e));
+                    tokio::time::sleep(backoff).await;
                 }
             }
+
+            retries += 1;
+        }
+    }
+
+    /// Whether the lyrics with this LRCLIB id were flagged in the local database. Always
+    /// false without a local database; lookup errors are logged and ignored.
+    pub async fn is_flagged(&self, lyrics_id: Option<i64>) -> bool {
+        match lyrics_id {
+            Some(id) => self.flagged_ids().await.contains(&id),
+            None => false,
+        }
+    }
+
+    async fn flagged_ids(&self) -> &HashSet<i64> {
+        self.flagged_ids.get_or_init(|| async {
+            let Some(db_path) = self.local_db_path.as_ref().filter(|path| path.exists()) else {
+                return HashSet::new();
+            };
+
+            let flagged = match LrclibDatabase::new(db_path).await {
+                Ok(lrclib_db) => lrclib_db.flagged_lyrics_ids().await,
+                Err(e) => Err(e),
+            };
+            flagged.unwrap_or_else(|e| {
+                warn!("Failed to read lyrics flags: {}", e);
+                HashSet::new()
+            })
+        }).await
+    }
+
+    async fn drop_flagged(&self, results: &mut Vec<SearchResult>) {
+        let flagged = self.flagged_ids().await;
+        if flagged.is_empty() {
+            return;
+        }
+
+        results.retain(|result| {
+            let keep = !result.id.is_some_and(|id| flagged.contains(&id));
+            if !keep {
+                debug!("Dropping flagged search result {:?}", result.id);
+            }
+            keep
+        });
+    }
+
+    /// Ask for a challenge to solve before publishing
+    pub async fn request_challenge(&self) -> Result<PublishChallenge> {
+        let url = format!("{}/api/request-challenge", self.base_url);
+        RateLimiter::global().acquire().await;
+        let response = self.client
+            .post(&url)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Challenge request error: {}", e))?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Challenge request failed: {}", api_error_message(response).await);
+        }
+
+        Ok(response.json().await?)
+    }
+
+    /// Publish lyrics. `publish_token` is `{prefix}:{nonce}` for a solved challenge.
+    /// Not retried: a token is only good for one publish.
+    pub async fn publish(&self, request: &PublishRequest, publish_token: &str) -> Result<()> {
+        let url = format!("{}/api/publish", self.base_url);
+        RateLimiter::global().acquire().await;
+        let response = self.client
+            .post(&url)
+            .header("X-Publish-Token", publish_token)
+            .json(request)
+            .send()
+            .await
+            .map_err(|e| anyhow::anyhow!("Publish request error: {}", e))?;
+
+        if !response.status().is_success() {
+            anyhow::bail!("Publish failed: {}", api_error_message(response).await);
         }
+
+        Ok(())
     }
 
     async fn search_local_db(
@@ -521,6 +721,20 @@ impl LrclibClient {
     }
 }
 
+/// Status line plus LRCLIB's error message, when the body has one
+async fn api_error_message(response: reqwest::Response) -> String {
+    let status = response.status();
+    match response.json::<ApiError>().await {
+        Ok(ApiError { message: Some(message) }) => format!("{} ({})", status, message),
+        _ => status.to_string(),
+    }
+}
+
+/// 429 and 5xx: the server is struggling, so retry later and send less
+fn is_overload_status(status: reqwest::StatusCode) -> bool {
+    status == reqwest::StatusCode::TOO_MANY_REQUESTS || status.is_server_error()
+}
+
 // Helper function to generate search variations for fuzzy matching
 fn generate_search_variations(title: &str, artist: &str, album: &str, query: &str) -> Vec<(String, String, String, String)> {
     let mut variations = Vec::new();
@@ -570,20 +784,20 @@ fn generate_search_variations(title: &str, artist: &str, album: &str, query: &st
 pub struct LyricsDownloader {
     client: LrclibClient,
     cache: Option<Arc<RwLock<LyricsCache>>>,
+    storage_policy: LyricsStoragePolicy,
+    duration_policy: DurationPolicy,
+    /// Look up tracks the cache remembers as not found instead of trusting that answer
+    bypass_negative_cache: bool,
 }
 
 impl LyricsDownloader {
-    pub fn new(lrclib_instance: &str) -> Self {
-        Self {
-            client: LrclibClient::new(lrclib_instance),
-            cache: None,
-        }
-    }
-
     pub fn from_client(client: LrclibClient) -> Self {
         Self {
             client,
             cache: None,
+            storage_policy: LyricsStoragePolicy::sidecar_only(),
+            duration_policy: DurationPolicy::default(),
+            bypass_negative_cache: false,
         }
     }
 
@@ -591,6 +805,9 @@ impl LyricsDownloader {
         Self {
             client: LrclibClient::new(lrclib_instance),
             cache: Some(cache),
+            storage_policy: LyricsStoragePolicy::sidecar_only(),
+            duration_policy: DurationPolicy::default(),
+            bypass_negative_cache: false,
         }
     }
 
@@ -598,7 +815,134 @@ impl LyricsDownloader {
         Self {
             client,
             cache: Some(cache),
+            storage_policy: LyricsStoragePolicy::sidecar_only(),
+            duration_policy: DurationPolicy::default(),
+            bypass_negative_cache: false,
+        }
+    }
+
+    /// Decide per format whether lyrics go to sidecar files, embedded tags or both
+    pub fn with_storage_policy(mut self, storage_policy: LyricsStoragePolicy) -> Self {
+        self.storage_policy = storage_policy;
+        self
+    }
+
+    /// Flag or reject lyrics whose length does not fit the track
+    pub fn with_duration_policy(mut self, duration_policy: DurationPolicy) -> Self {
+        self.duration_policy = duration_policy;
+        self
+    }
+
+    /// Share an adaptive concurrency limit with the other downloaders' clients
+    pub fn with_concurrency(mut self, concurrency: Arc<AdaptiveConcurrency>) -> Self {
+        self.client = self.client.with_concurrency(concurrency);
+        self
+    }
+
+    /// Ignore cached "not found" answers, refreshing them with a new lookup
+    pub fn with_bypass_negative_cache(mut self, bypass: bool) -> Self {
+        self.bypass_negative_cache = bypass;
+        self
+    }
+
+    /// Compare lyrics with the track length; a mismatch is logged either way and
+    /// `reject` is set when the policy says not to save them.
+    fn check_duration(
+        &self,
+        track: &DatabaseTrack,
+        result_duration: Option<f64>,
+        synced_lyrics: Option<&str>,
+    ) -> (Option<DurationCheck>, bool) {
+        let check = check_duration(track.duration, result_duration, synced_lyrics, self.duration_policy.threshold_seconds);
+
+        let reject = match check {
+            Some(check) if check.mismatch => {
+                warn!("Duration mismatch for {} - {}: {}", track.artist_name, track.title, check.describe());
+                self.duration_policy.action == DurationMismatchAction::Reject
+            }
+            _ => false,
+        };
+
+        (check, reject)
+    }
+
+    /// Write lyrics according to the storage policy. Embedding failures are only
+    /// logged when a sidecar was written as well.
+    async fn store_lyrics(
+        &self,
+        track: &DatabaseTrack,
+        plain_lyrics: Option<&str>,
+        synced_lyrics: Option<&str>,
+        is_instrumental: bool,
+    ) -> Result<()> {
+        let lyrics_manager = LyricsManager::new();
+        let storage = self.storage_policy.for_path(&track.file_path);
+
+        if is_instrumental || storage.writes_sidecar() {
+            lyrics_manager.save_lyrics_for_track(track, plain_lyrics, synced_lyrics, is_instrumental).await?;
+        }
+
+        if !is_instrumental && storage.embeds() {
+            if let Err(e) = lyrics_manager.embed_lyrics(&track.file_path, plain_lyrics, synced_lyrics) {
+                if storage.writes_sidecar() {
+                    warn!("Failed to embed lyrics for {} - {}: {}", track.artist_name, track.title, e);
+                } else {
+                    return Err(e);
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Save a search result picked for a track, with the same duration check and
+    /// storage policy as a download
+    pub async fn apply_search_result(&self, track: &DatabaseTrack, result: &SearchResult) -> Result<LyricsDownloadResult> {
+        // Picked results may ignore duration, so the length check matters most here
+        let (check, reject) = if result.instrumental {
+            (None, false)
+        } else {
+            self.check_duration(track, result.duration, result.synced_lyrics.as_deref())
+        };
+        if reject {
+            return Ok(LyricsDownloadResult {
+                found: true,
+                source: Some(result.source),
+                lrclib_id: result.id,
+                confidence: check.map(|c| c.confidence),
+                rejected: true,
+                ..LyricsDownloadResult::not_found()
+            });
         }
+
+        self.store_lyrics(
+            track,
+            result.plain_lyrics.as_deref(),
+            result.synced_lyrics.as_deref(),
+            result.instrumental,
+        ).await?;
+
+        let has_synced = result.synced_lyrics.is_some();
+        let has_plain = result.plain_lyrics.is_some();
+
+        if has_synced {
+            debug!("Saved synced lyrics for: {}", track.title);
+        }
+
+        if has_plain {
+            debug!("Saved plain lyrics for: {}", track.title);
+        }
+
+        Ok(LyricsDownloadResult {
+            found: true,
+            instrumental: result.instrumental,
+            synced_lyrics: has_synced,
+            plain_lyrics: has_plain,
+            source: Some(result.source),
+            lrclib_id: result.id,
+            confidence: check.map(|c| c.confidence),
+            rejected: false,
+        })
     }
 
     pub async fn download_for_track(&self, track: &DatabaseTrack) -> Result<LyricsDownloadResult> {
@@ -612,13 +956,27 @@ impl LyricsDownloader {
         let lyrics = if let Some(cache) = &self.cache {
             // Use a scope to minimize lock duration
             let cached_lyrics = {
-                let mut cache_guard = cache.write().await; // Need write for get() due to stats updates
-                cache_guard.get(&track.title, &track.artist_name, &track.album_name, track.duration).await
+                let mut cache_guard = cache.write().await; // Need write for lookup() due to stats updates
+                cache_guard.lookup(&track.title, &track.artist_name, &track.album_name, track.duration).await
             };
 
-            if let Some(cached_lyrics) = cached_lyrics {
+            // Lyrics flagged after they were cached are looked up again
+            let cached_lyrics = match cached_lyrics {
+                Some(CachedLyrics::Found(lyrics))
+                    if self.client.is_flagged(lyrics.id).await =>
+                {
+                    debug!("Cached lyrics are flagged, looking up again: {} - {}", track.artist_name, track.title);
+                    None
+                }
+                other => other,
+            };
+
+            if let Some(CachedLyrics::Found(cached_lyrics)) = cached_lyrics {
                 debug!("Using cached lyrics for: {} - {}", track.artist_name, track.title);
-                Some(cached_lyrics)
+                Some((cached_lyrics, SearchResultSource::Cache))
+            } else if matches!(cached_lyrics, Some(CachedLyrics::Missing)) && !self.bypass_negative_cache {
+                debug!("Lyrics recently not found, skipping lookup for: {} - {}", track.artist_name, track.title);
+                None
             } else {
                 // Cache miss, fetch from API
                 let lyrics = self.client.get_lyrics(
@@ -628,16 +986,24 @@ impl LyricsDownloader {
                     track.duration,
                 ).await?;
 
-                // Cache the result if we got one
-                if let Some(ref lyrics_data) = lyrics {
-                    let lyrics_response: LyricsResponse = (*lyrics_data).clone().into();
-                    let mut cache_write = cache.write().await;
-                    if let Err(e) = cache_write.put(&track.title, &track.artist_name, &track.album_name, track.duration, lyrics_response).await {
-                        debug!("Failed to cache lyrics for {} - {}: {}", track.artist_name, track.title, e);
+                // Cache the result, remembering misses as well
+                let mut cache_write = cache.write().await;
+                let cached = match lyrics {
+                    Some(ref lyrics_data) => {
+                        let lyrics_response: LyricsResponse = (*lyrics_data).clone().into();
+                        cache_write.put(&track.title, &track.artist_name, &track.album_name, track.duration, lyrics_response).await
                     }
+                    None => cache_write.put_missing(&track.title, &track.artist_name, &track.album_name, track.duration).await,
+                };
+                if let Err(e) = cached {
+                    debug!("Failed to cache lyrics for {} - {}: {}", track.artist_name, track.title, e);
                 }
+                drop(cache_write);
 
-                lyrics.map(|l| l.into())
+                lyrics.map(|l| {
+                    let source = l.source;
+                    (l.into(), source)
+                })
             }
         } else {
             // No cache, direct API call
@@ -646,26 +1012,42 @@ impl LyricsDownloader {
                 &track.artist_name,
                 &track.album_name,
                 track.duration,
-            ).await?.map(|l| l.into())
+            ).await?.map(|l| {
+                let source = l.source;
+                (l.into(), source)
+            })
         };
 
-        if let Some(lyrics_data) = lyrics {
+        if let Some((lyrics_data, source)) = lyrics {
             if lyrics_data.instrumental {
                 debug!("Track is marked as instrumental: {}", track.title);
-                // TODO: Save instrumental marker
+                self.store_lyrics(track, None, None, true).await?;
                 return Ok(LyricsDownloadResult {
                     found: true,
                     instrumental: true,
                     synced_lyrics: false,
                     plain_lyrics: false,
+                    source: Some(source),
+                    lrclib_id: lyrics_data.id,
+                    confidence: None,
+                    rejected: false,
                 });
             }
 
-            // Save lyrics to files
-            use crate::core::files::lyrics::LyricsManager;
-            let lyrics_manager = LyricsManager::new();
+            let (check, reject) = self.check_duration(track, lyrics_data.duration, lyrics_data.synced_lyrics.as_deref());
+            if reject {
+                return Ok(LyricsDownloadResult {
+                    found: true,
+                    source: Some(source),
+                    lrclib_id: lyrics_data.id,
+                    confidence: check.map(|c| c.confidence),
+                    rejected: true,
+                    ..LyricsDownloadResult::not_found()
+                });
+            }
 
-            lyrics_manager.save_lyrics_for_track(
+            // Save lyrics to files and/or tags
+            self.store_lyrics(
                 track,
                 lyrics_data.plain_lyrics.as_deref(),
                 lyrics_data.synced_lyrics.as_deref(),
@@ -688,6 +1070,10 @@ impl LyricsDownloader {
                 instrumental: false,
                 synced_lyrics: has_synced,
                 plain_lyrics: has_plain,
+                source: Some(source),
+                lrclib_id: lyrics_data.id,
+                confidence: check.map(|c| c.confidence),
+                rejected: false,
             })
         } else if use_fuzzy {
             debug!("No exact match found, trying fuzzy search for: {} - {}", track.artist_name, track.title);
@@ -702,52 +1088,133 @@ impl LyricsDownloader {
 
             if let Some(best_match) = fuzzy_results.first() {
                 debug!("Found fuzzy match for: {} - {}", track.artist_name, track.title);
-
-                // Use the same save logic as above
-                use crate::core::files::lyrics::LyricsManager;
-                let lyrics_manager = LyricsManager::new();
-
-                lyrics_manager.save_lyrics_for_track(
-                    track,
-                    best_match.plain_lyrics.as_deref(),
-                    best_match.synced_lyrics.as_deref(),
-                    best_match.instrumental,
-                ).await?;
-
-                let has_synced = best_match.synced_lyrics.is_some();
-                let has_plain = best_match.plain_lyrics.is_some();
-
-                if has_synced {
-                    debug!("Found and saved synced lyrics via fuzzy search for: {}", track.title);
-                }
-
-                if has_plain {
-                    debug!("Found and saved plain lyrics via fuzzy search for: {}", track.title);
-                }
-
-                Ok(LyricsDownloadResult {
-                    found: true,
-                    instrumental: best_match.instrumental,
-                    synced_lyrics: has_synced,
-                    plain_lyrics: has_plain,
-                })
+                self.apply_search_result(track, best_match).await
             } else {
                 debug!("No fuzzy match found for: {} - {}", track.artist_name, track.title);
-                Ok(LyricsDownloadResult {
-                    found: false,
-                    instrumental: false,
-                    synced_lyrics: false,
-                    plain_lyrics: false,
-                })
+                Ok(LyricsDownloadResult::not_found())
             }
         } else {
             debug!("No lyrics found for: {} - {}", track.artist_name, track.title);
-            Ok(LyricsDownloadResult {
-                found: false,
-                instrumental: false,
-                synced_lyrics: false,
-                plain_lyrics: false,
-            })
+            Ok(LyricsDownloadResult::not_found())
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_dir::TestDir;
+
+    fn track(file_path: &str) -> DatabaseTrack {
+        DatabaseTrack {
+            id: 1,
+            file_path: file_path.to_string(),
+            file_name: "song.mp3".to_string(),
+            title: "Song".to_string(),
+            album_name: "Album".to_string(),
+            artist_name: "Artist".to_string(),
+            album_artist: "Artist".to_string(),
+            duration: 180.0,
+            track_number: None,
+            txt_lyrics: None,
+            lrc_lyrics: None,
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            instrumental: false,
+        }
+    }
+
+    fn search_result(id: i64, duration: f64) -> SearchResult {
+        SearchResult {
+            id: Some(id),
+            name: Some("Song".to_string()),
+            artist_name: Some("Artist".to_string()),
+            album_name: Some("Album".to_string()),
+            duration: Some(duration),
+            plain_lyrics: Some("Words".to_string()),
+            synced_lyrics: Some("[00:01.00]Words".to_string()),
+            instrumental: false,
+            source: SearchResultSource::Api,
+        }
+    }
+
+    /// Serve canned `(path, status, body)` responses on a local port, ignoring query strings
+    async fn serve(routes: Vec<(&'static str, u16, &'static str)>) -> String {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    match stream.read(&mut buffer).await {
+                        Ok(0) | Err(_) => break,
+                        Ok(n) => request.extend_from_slice(&buffer[..n]),
+                    }
+                }
+
+                let request = String::from_utf8_lossy(&request);
+                let target = request.split_whitespace().nth(1).unwrap_or("");
+                let path = target.split('?').next().unwrap_or("");
+                let (status, body) = routes.iter()
+                    .find(|(route, _, _)| *route == path)
+                    .map(|(_, status, body)| (*status, *body))
+                    .unwrap_or((404, r#"{"message":"Not found"}"#));
+                let response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status, body.len(), body
+                );
+                let _ = stream.write_all(response.as_bytes()).await;
+            }
+        });
+        base_url
+    }
+
+    const RECORD_7: &str = r#"{"id":7,"name":"Song","artistName":"Artist","albumName":"Album","duration":180.0,
+        "plainLyrics":"Words","syncedLyrics":"[00:01.00]Words","instrumental":false}"#;
+
+    #[tokio::test]
+    async fn test_get_lyrics_by_id_maps_404_to_none() {
+        let base_url = serve(vec![("/api/get/7", 200, RECORD_7), ("/api/get/8", 404, r#"{"message":"Not found"}"#)]).await;
+        let client = LrclibClient::new(&base_url);
+
+        let record = client.get_lyrics_by_id(7).await.unwrap().unwrap();
+        assert_eq!((record.id, record.synced_lyrics.as_deref()), (Some(7), Some("[00:01.00]Words")));
+        assert!(client.get_lyrics_by_id(8).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_lookup_drops_flagged_records() {
+        let base_url = serve(vec![("/api/get", 200, RECORD_7), ("/api/get-cached", 200, RECORD_7)]).await;
+
+        let client = LrclibClient::new(&base_url);
+        assert_eq!(client.get_lyrics("Song", "Artist", "Album", 180.0).await.unwrap().unwrap().id, Some(7));
+
+        let client = LrclibClient::new(&base_url);
+        client.flagged_ids.set(HashSet::from([7])).unwrap();
+        assert!(client.get_lyrics("Song", "Artist", "Album", 180.0).await.unwrap().is_none());
+        assert!(client.get_lyrics_cached("Song", "Artist", "Album", 180.0).await.unwrap().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_apply_search_result_stores_lyrics_and_rejects_mismatches() {
+        let test_dir = TestDir::new("lrclib");
+        let dir = test_dir.path();
+        let track = track(&dir.join("song.mp3").display().to_string());
+        let downloader = LyricsDownloader::from_client(LrclibClient::new("http://127.0.0.1:9")).with_duration_policy(DurationPolicy {
+            threshold_seconds: 10.0,
+            action: DurationMismatchAction::Reject,
+        });
+
+        let result = downloader.apply_search_result(&track, &search_result(5, 400.0)).await.unwrap();
+        assert!(result.rejected);
+        assert_eq!(result.outcome(), DownloadOutcome::Mismatch);
+        assert!(!dir.join("song.lrc").exists());
+
+        let result = downloader.apply_search_result(&track, &search_result(6, 181.0)).await.unwrap();
+        assert_eq!((result.outcome(), result.lrclib_id), (DownloadOutcome::Synced, Some(6)));
+        assert_eq!(std::fs::read_to_string(dir.join("song.lrc")).unwrap(), "[00:01.00]Words");
+    }
+}
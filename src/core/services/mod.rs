@@ -3,7 +3,13 @@
 //! This module contains integrations with external APIs and services:
 //! - LRCLIB API client for lyrics retrieval
 //! - Lyrics downloader with caching support
+//! - Proof-of-work challenge solving for publishing to LRCLIB
+//! - Process-wide rate limiting of LRCLIB requests
+//! - Adaptive download concurrency driven by LRCLIB responses
 
+pub mod challenge;
+pub mod concurrency;
 pub mod lrclib;
+pub mod rate_limit;
 
 // Re-export main types
@@ -0,0 +1,168 @@
+use chrono::{DateTime, Utc};
+use std::sync::{Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+/// Longest `Retry-After` we honour, so a bogus header cannot stall a run for hours
+const MAX_RETRY_AFTER: Duration = Duration::from_secs(300);
+
+/// Request rate and retry budget shared by every LRCLIB request in the process
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateLimitPolicy {
+    pub requests_per_second: f64,
+    /// Retries after the first attempt on 429, 5xx and network errors
+    pub max_retries: u32,
+}
+
+impl Default for RateLimitPolicy {
+    fn default() -> Self {
+        Self {
+            requests_per_second: 5.0,
+            max_retries: 3,
+        }
+    }
+}
+
+/// Token bucket holding one second's worth of requests, kept as the time the bucket
+/// would next be empty (GCRA), plus a pause set from `Retry-After`.
+struct LimiterState {
+    interval: Duration,
+    burst: u32,
+    max_retries: u32,
+    /// Theoretical arrival time of the next request
+    next_slot: Option<Instant>,
+    paused_until: Option<Instant>,
+}
+
+impl LimiterState {
+    fn new(policy: RateLimitPolicy) -> Self {
+        let mut state = Self {
+            interval: Duration::ZERO,
+            burst: 1,
+            max_retries: 0,
+            next_slot: None,
+            paused_until: None,
+        };
+        state.apply(policy);
+        state
+    }
+
+    fn apply(&mut self, policy: RateLimitPolicy) {
+        let rate = policy.requests_per_second.max(0.01);
+        self.interval = Duration::from_secs_f64(1.0 / rate);
+        self.burst = rate.ceil().max(1.0) as u32;
+        self.max_retries = policy.max_retries;
+    }
+
+    /// Take a slot and return how long to wait before using it
+    fn reserve(&mut self, now: Instant) -> Duration {
+        let slot = self.next_slot.map_or(now, |slot| slot.max(now));
+        self.next_slot = Some(slot + self.interval);
+
+        let tolerance = self.interval * (self.burst - 1);
+        let mut allowed_at = slot.checked_sub(tolerance).unwrap_or(now);
+        if let Some(paused_until) = self.paused_until {
+            allowed_at = allowed_at.max(paused_until);
+        }
+        allowed_at.saturating_duration_since(now)
+    }
+
+    /// Hold every request until `until`; the rate restarts from there
+    fn pause_until(&mut self, until: Instant) {
+        self.paused_until = Some(self.paused_until.map_or(until, |paused| paused.max(until)));
+        self.next_slot = Some(self.next_slot.map_or(until, |slot| slot.max(until)));
+    }
+}
+
+/// Process-wide limiter for LRCLIB requests. Every `LrclibClient` shares it, so the
+/// rate holds however many clients or parallel downloads are running.
+pub struct RateLimiter {
+    state: Mutex<LimiterState>,
+}
+
+impl RateLimiter {
+    fn new(policy: RateLimitPolicy) -> Self {
+        Self {
+            state: Mutex::new(LimiterState::new(policy)),
+        }
+    }
+
+    /// The shared limiter, created with the default policy until `configure` is called
+    pub fn global() -> &'static RateLimiter {
+        static GLOBAL: OnceLock<RateLimiter> = OnceLock::new();
+        GLOBAL.get_or_init(|| RateLimiter::new(RateLimitPolicy::default()))
+    }
+
+    /// Apply the configured rate and retry budget
+    pub fn configure(&self, policy: RateLimitPolicy) {
+        self.lock().apply(policy);
+    }
+
+    pub fn max_retries(&self) -> u32 {
+        self.lock().max_retries
+    }
+
+    /// Wait for a request slot
+    pub async fn acquire(&self) {
+        let wait = self.lock().reserve(Instant::now());
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Stop all requests for `delay`, e.g. after a 429 with `Retry-After`
+    pub fn pause_for(&self, delay: Duration) {
+        let delay = delay.min(MAX_RETRY_AFTER);
+        warn!("LRCLIB asked to slow down; pausing requests for {:.1}s", delay.as_secs_f64());
+        self.lock().pause_until(Instant::now() + delay);
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, LimiterState> {
+        // The state stays consistent even if a holder panicked
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// Parse a `Retry-After` value: delay in seconds or an HTTP date
+pub fn parse_retry_after(value: &str, now: DateTime<Utc>) -> Option<Duration> {
+    let value = value.trim();
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some((date.with_timezone(&Utc) - now).to_std().unwrap_or(Duration::ZERO))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_spaces_requests_after_burst() {
+        let mut state = LimiterState::new(RateLimitPolicy { requests_per_second: 2.0, max_retries: 3 });
+        let start = Instant::now();
+
+        // A burst of two, then one every 500ms
+        assert_eq!(state.reserve(start), Duration::ZERO);
+        assert_eq!(state.reserve(start), Duration::ZERO);
+        assert_eq!(state.reserve(start), Duration::from_millis(500));
+        assert_eq!(state.reserve(start), Duration::from_millis(1000));
+
+        // A pause holds everyone, then the rate restarts from its end
+        let later = start + Duration::from_secs(10);
+        state.pause_until(later + Duration::from_secs(3));
+        assert_eq!(state.reserve(later), Duration::from_secs(3));
+        assert_eq!(state.reserve(later), Duration::from_secs(3));
+        assert_eq!(state.reserve(later), Duration::from_millis(3500));
+    }
+
+    #[test]
+    fn test_parse_retry_after() {
+        let now = DateTime::parse_from_rfc3339("2015-10-21T07:28:00Z").unwrap().with_timezone(&Utc);
+        assert_eq!(parse_retry_after("120", now), Some(Duration::from_secs(120)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:28:30 GMT", now), Some(Duration::from_secs(30)));
+        assert_eq!(parse_retry_after("Wed, 21 Oct 2015 07:27:00 GMT", now), Some(Duration::ZERO));
+        assert_eq!(parse_retry_after("soon", now), None);
+    }
+}
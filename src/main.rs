@@ -46,6 +46,9 @@ enum Commands {
     /// Search for lyrics manually
     Search(search::SearchArgs),
     
+    /// Browse the library and review lyrics interactively
+    Browse(browse::BrowseArgs),
+    
     /// Fetch lyrics for a specific file
     Fetch(fetch::FetchArgs),
     
@@ -69,6 +72,18 @@ enum Commands {
     
     /// Watch directory for new files and auto-download lyrics
     Watch(cli::watch::WatchArgs),
+    
+    /// Check .lrc files next to library tracks for problems
+    Lint(cli::lint::LintArgs),
+    
+    /// Shift the timing of a track's synced lyrics
+    Shift(cli::shift::ShiftArgs),
+    
+    /// Publish a track's local lyrics to LRCLIB
+    Publish(cli::publish::PublishArgs),
+    
+    /// Flag a track's lyrics as wrong so they are not downloaded again
+    Flag(cli::flag::FlagArgs),
 }
 
 #[tokio::main]
@@ -86,6 +101,9 @@ async fn main() -> Result<()> {
     // Load configuration
     let config = Config::load(cli.config.as_deref())?;
 
+    // Every LRCLIB client in the process shares one rate limiter
+    core::services::rate_limit::RateLimiter::global().configure(config.rate_limit_policy());
+
     // Initialize services
     let services = SimpleServices::new(config);
 
@@ -97,6 +115,7 @@ async fn main() -> Result<()> {
         Commands::Scan(args) => scan::execute(args, &services).await,
         Commands::Download(args) => download::execute(args, &config).await,
         Commands::Search(args) => search::execute(args, &services).await,
+        Commands::Browse(args) => browse::execute(args, &services).await,
         Commands::Fetch(args) => fetch::execute(args, &config).await
             .map_err(|e| error::LrcGetError::Internal(e)),
         Commands::Config(args) => cli::config::execute(args, &config).await,
@@ -111,5 +130,9 @@ async fn main() -> Result<()> {
             .map_err(|e| error::LrcGetError::Internal(e)),
         Commands::Watch(args) => cli::watch::execute(args, &config).await
             .map_err(|e| error::LrcGetError::Internal(e)),
+        Commands::Lint(args) => cli::lint::execute(args, &services).await,
+        Commands::Shift(args) => cli::shift::execute(args, &services).await,
+        Commands::Publish(args) => cli::publish::execute(args, &services).await,
+        Commands::Flag(args) => cli::flag::execute(args, &services).await,
     }
 }
\ No newline at end of file
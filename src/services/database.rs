@@ -100,7 +100,7 @@ impl DatabaseService for MockDatabaseService {
                 .collect())
         } else if query == "has_lyrics:false" {
             Ok(tracks.iter()
-                .filter(|t| t.lrc_lyrics.is_none() && t.txt_lyrics.is_none())
+                .filter(|t| t.lrc_lyrics.is_none() && t.txt_lyrics.is_none() && !t.instrumental)
                 .cloned()
                 .collect())
         } else {
@@ -1,6 +1,8 @@
 use std::sync::Arc;
+use tokio::sync::RwLock;
 use crate::config::Config;
 use crate::core::data::database::Database;
+use crate::core::infrastructure::cache::LyricsCache;
 use crate::core::services::lrclib::{LrclibClient, LyricsDownloader};
 use crate::core::files::scanner::Scanner;
 use crate::error::Result;
@@ -37,10 +39,14 @@ impl ServiceFactory {
         LrclibClient::new(&self.config.lrclib_instance)
     }
 
-    /// Create lyrics downloader
-    /// Replaces `LyricsDownloader::new(&config.lrclib_instance)` patterns
-    pub fn create_lyrics_downloader(&self) -> LyricsDownloader {
-        LyricsDownloader::new(&self.config.lrclib_instance)
+    /// Create the lyrics cache shared by downloaders; save its index once they are done
+    pub fn create_lyrics_cache(&self) -> Result<Arc<RwLock<LyricsCache>>> {
+        Ok(Arc::new(RwLock::new(self.config.create_lyrics_cache()?)))
+    }
+
+    /// Create lyrics downloader using the shared cache
+    pub fn create_lyrics_downloader(&self, cache: Arc<RwLock<LyricsCache>>) -> LyricsDownloader {
+        self.config.create_lyrics_downloader(Some(cache))
     }
 
     /// Create scanner instance
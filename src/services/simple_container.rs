@@ -1,5 +1,7 @@
 use std::sync::Arc;
+use tokio::sync::RwLock;
 use crate::config::Config;
+use crate::core::infrastructure::cache::LyricsCache;
 use crate::error::Result;
 
 pub struct SimpleServices {
@@ -28,10 +30,21 @@ impl SimpleServices {
     }
 
     pub fn create_lrclib_client(&self) -> crate::core::services::lrclib::LrclibClient {
-        crate::core::services::lrclib::LrclibClient::new(&self.config.lrclib_instance)
+        self.config.create_lrclib_client()
     }
 
+    /// Lyrics cache shared by downloaders; save its index once they are done
+    pub fn create_lyrics_cache(&self) -> Result<Arc<RwLock<LyricsCache>>> {
+        Ok(Arc::new(RwLock::new(self.config.create_lyrics_cache()?)))
+    }
+
+    /// Downloader using the shared lyrics cache
+    pub fn create_cached_downloader(&self, cache: Arc<RwLock<LyricsCache>>) -> crate::core::services::lrclib::LyricsDownloader {
+        self.config.create_lyrics_downloader(Some(cache))
+    }
+
+    /// Downloader without a lyrics cache, for saving lyrics the user picked
     pub fn create_downloader(&self) -> crate::core::services::lrclib::LyricsDownloader {
-        crate::core::services::lrclib::LyricsDownloader::new(&self.config.lrclib_instance)
+        self.config.create_lyrics_downloader(None)
     }
 }
\ No newline at end of file
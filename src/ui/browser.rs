@@ -0,0 +1,471 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+
+use crate::core::data::database::DatabaseTrack;
+use crate::core::services::lrclib::SearchResult;
+use crate::ui::picker::{PickerAction, SearchPicker};
+use crate::ui::tui::ACCENT;
+
+const LYRICS_PAGE: u16 = 10;
+
+/// Lyrics status a track must have to be listed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusFilter {
+    All,
+    Missing,
+    Synced,
+    Plain,
+    Instrumental,
+}
+
+impl StatusFilter {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value.to_lowercase().as_str() {
+            "all" => Some(Self::All),
+            "missing" => Some(Self::Missing),
+            "synced" => Some(Self::Synced),
+            "plain" => Some(Self::Plain),
+            "instrumental" => Some(Self::Instrumental),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::All => "all",
+            Self::Missing => "missing",
+            Self::Synced => "synced",
+            Self::Plain => "plain",
+            Self::Instrumental => "instrumental",
+        }
+    }
+
+    fn next(self) -> Self {
+        match self {
+            Self::All => Self::Missing,
+            Self::Missing => Self::Synced,
+            Self::Synced => Self::Plain,
+            Self::Plain => Self::Instrumental,
+            Self::Instrumental => Self::All,
+        }
+    }
+
+    fn matches(self, track: &DatabaseTrack) -> bool {
+        match self {
+            Self::All => true,
+            Self::Missing => track.is_missing_lyrics(),
+            Self::Synced => !track.instrumental && track.lrc_lyrics.is_some(),
+            Self::Plain => !track.instrumental && track.lrc_lyrics.is_none() && track.txt_lyrics.is_some(),
+            Self::Instrumental => track.instrumental,
+        }
+    }
+}
+
+/// Filters applied to the library listing
+#[derive(Debug, Clone)]
+pub struct LibraryFilter {
+    pub artist: Option<String>,
+    pub album: Option<String>,
+    pub status: StatusFilter,
+    /// Free text matched against title, artist and album
+    pub query: String,
+}
+
+impl LibraryFilter {
+    fn matches(&self, track: &DatabaseTrack) -> bool {
+        let contains = |field: &str, needle: &str| field.to_lowercase().contains(&needle.to_lowercase());
+
+        if let Some(artist) = &self.artist {
+            if !contains(&track.artist_name, artist) {
+                return false;
+            }
+        }
+        if let Some(album) = &self.album {
+            if !contains(&track.album_name, album) {
+                return false;
+            }
+        }
+        if !self.query.is_empty()
+            && !contains(&track.title, &self.query)
+            && !contains(&track.artist_name, &self.query)
+            && !contains(&track.album_name, &self.query)
+        {
+            return false;
+        }
+
+        self.status.matches(track)
+    }
+}
+
+/// Work the browser asks its caller to perform
+pub enum BrowserAction {
+    None,
+    Quit,
+    Reload,
+    Search(DatabaseTrack),
+    Download(DatabaseTrack),
+    Delete(DatabaseTrack),
+    Apply(DatabaseTrack, SearchResult),
+}
+
+enum BrowserMode {
+    Browse,
+    EditQuery,
+    ConfirmDelete,
+    Picking {
+        track: Box<DatabaseTrack>,
+        picker: SearchPicker,
+    },
+}
+
+/// Full-screen library browser: track list, lyrics pane and per-track actions
+pub struct LibraryBrowser {
+    tracks: Vec<DatabaseTrack>,
+    visible: Vec<usize>,
+    filter: LibraryFilter,
+    list_state: ListState,
+    lyrics_scroll: u16,
+    mode: BrowserMode,
+    message: Option<String>,
+}
+
+impl LibraryBrowser {
+    pub fn new(tracks: Vec<DatabaseTrack>, filter: LibraryFilter) -> Self {
+        let mut browser = Self {
+            tracks,
+            visible: Vec::new(),
+            filter,
+            list_state: ListState::default(),
+            lyrics_scroll: 0,
+            mode: BrowserMode::Browse,
+            message: None,
+        };
+        browser.apply_filter();
+        browser
+    }
+
+    pub fn set_message(&mut self, message: impl Into<String>) {
+        self.message = Some(message.into());
+    }
+
+    /// Replace the whole library, keeping the current selection when it is still listed
+    pub fn set_tracks(&mut self, tracks: Vec<DatabaseTrack>) {
+        self.tracks = tracks;
+        self.apply_filter();
+    }
+
+    /// Swap in a refreshed copy of a single track
+    pub fn replace_track(&mut self, track: DatabaseTrack) {
+        if let Some(existing) = self.tracks.iter_mut().find(|t| t.id == track.id) {
+            *existing = track;
+        }
+        self.apply_filter();
+    }
+
+    pub fn open_picker(&mut self, track: DatabaseTrack, results: Vec<SearchResult>) {
+        let header = format!(
+            "Apply lyrics to #{}: {} - {} ({})",
+            track.id, track.artist_name, track.title, track.album_name
+        );
+        self.mode = BrowserMode::Picking {
+            track: Box::new(track),
+            picker: SearchPicker::new(results, header),
+        };
+    }
+
+    fn selected_track(&self) -> Option<&DatabaseTrack> {
+        self.list_state
+            .selected()
+            .and_then(|i| self.visible.get(i))
+            .map(|&index| &self.tracks[index])
+    }
+
+    fn apply_filter(&mut self) {
+        let selected_id = self.selected_track().map(|t| t.id);
+
+        self.visible = self.tracks
+            .iter()
+            .enumerate()
+            .filter(|(_, track)| self.filter.matches(track))
+            .map(|(index, _)| index)
+            .collect();
+
+        let position = selected_id
+            .and_then(|id| self.visible.iter().position(|&i| self.tracks[i].id == id))
+            .or(if self.visible.is_empty() { None } else { Some(0) });
+        self.select(position);
+    }
+
+    fn select(&mut self, position: Option<usize>) {
+        if position != self.list_state.selected() {
+            self.lyrics_scroll = 0;
+        }
+        self.list_state.select(position);
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.visible.is_empty() {
+            return;
+        }
+        let current = self.list_state.selected().unwrap_or(0) as isize;
+        let last = self.visible.len() as isize - 1;
+        self.select(Some((current + delta).clamp(0, last) as usize));
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> BrowserAction {
+        if key.kind != KeyEventKind::Press {
+            return BrowserAction::None;
+        }
+
+        match &mut self.mode {
+            BrowserMode::Picking { picker, .. } => match picker.handle_key(key) {
+                PickerAction::Select(index) => {
+                    let result = picker.results()[index].clone();
+                    match std::mem::replace(&mut self.mode, BrowserMode::Browse) {
+                        BrowserMode::Picking { track, .. } => BrowserAction::Apply(*track, result),
+                        _ => BrowserAction::None,
+                    }
+                }
+                PickerAction::Cancel => {
+                    self.mode = BrowserMode::Browse;
+                    self.set_message("Search cancelled");
+                    BrowserAction::None
+                }
+                PickerAction::None => BrowserAction::None,
+            },
+            BrowserMode::EditQuery => {
+                match key.code {
+                    KeyCode::Enter | KeyCode::Esc => self.mode = BrowserMode::Browse,
+                    KeyCode::Backspace => {
+                        self.filter.query.pop();
+                        self.apply_filter();
+                    }
+                    KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        self.filter.query.push(c);
+                        self.apply_filter();
+                    }
+                    _ => {}
+                }
+                BrowserAction::None
+            }
+            BrowserMode::ConfirmDelete => {
+                self.mode = BrowserMode::Browse;
+                if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                    if let Some(track) = self.selected_track() {
+                        return BrowserAction::Delete(track.clone());
+                    }
+                }
+                self.set_message("Delete cancelled");
+                BrowserAction::None
+            }
+            BrowserMode::Browse => self.handle_browse_key(key),
+        }
+    }
+
+    fn handle_browse_key(&mut self, key: KeyEvent) -> BrowserAction {
+        self.message = None;
+
+        match key.code {
+            KeyCode::Char('q') => return BrowserAction::Quit,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return BrowserAction::Quit,
+            KeyCode::Esc if self.filter.query.is_empty() => return BrowserAction::Quit,
+            KeyCode::Esc => {
+                self.filter.query.clear();
+                self.apply_filter();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.move_selection(-1),
+            KeyCode::Down | KeyCode::Char('j') => self.move_selection(1),
+            KeyCode::Home => self.move_selection(isize::MIN / 2),
+            KeyCode::End => self.move_selection(isize::MAX / 2),
+            KeyCode::PageDown => self.lyrics_scroll = self.lyrics_scroll.saturating_add(LYRICS_PAGE),
+            KeyCode::PageUp => self.lyrics_scroll = self.lyrics_scroll.saturating_sub(LYRICS_PAGE),
+            KeyCode::Char('/') => self.mode = BrowserMode::EditQuery,
+            KeyCode::Tab => {
+                self.filter.status = self.filter.status.next();
+                self.apply_filter();
+            }
+            KeyCode::Char('r') => return BrowserAction::Reload,
+            KeyCode::Char('s') => {
+                if let Some(track) = self.selected_track() {
+                    return BrowserAction::Search(track.clone());
+                }
+            }
+            KeyCode::Char('d') => {
+                if let Some(track) = self.selected_track() {
+                    return BrowserAction::Download(track.clone());
+                }
+            }
+            KeyCode::Char('x') | KeyCode::Delete if self.selected_track().is_some() => {
+                self.mode = BrowserMode::ConfirmDelete;
+            }
+            _ => {}
+        }
+
+        BrowserAction::None
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame) {
+        let area = frame.size();
+
+        if let BrowserMode::Picking { picker, .. } = &mut self.mode {
+            picker.draw(frame, area);
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(5), Constraint::Length(1)])
+            .split(area);
+
+        self.draw_header(frame, rows[0]);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(rows[1]);
+
+        let items: Vec<ListItem> = self.visible
+            .iter()
+            .map(|&index| track_list_item(&self.tracks[index]))
+            .collect();
+        let list = List::new(items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(ACCENT))
+                .title(format!(" Tracks ({}/{}) ", self.visible.len(), self.tracks.len())))
+            .highlight_style(Style::default().fg(Color::Black).bg(ACCENT))
+            .highlight_symbol("▶ ");
+        frame.render_stateful_widget(list, columns[0], &mut self.list_state);
+
+        let (title, text) = match self.selected_track() {
+            Some(track) => lyrics_view(track),
+            None => (" Lyrics ".to_string(), "No tracks match the current filters".to_string()),
+        };
+        let lyrics = Paragraph::new(text)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(ACCENT))
+                .title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.lyrics_scroll, 0));
+        frame.render_widget(lyrics, columns[1]);
+
+        self.draw_footer(frame, rows[2]);
+    }
+
+    fn draw_header(&self, frame: &mut Frame, area: Rect) {
+        let mut spans = vec![
+            Span::styled("Library", Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)),
+            Span::raw(format!("  status: {}", self.filter.status.as_str())),
+        ];
+        if let Some(artist) = &self.filter.artist {
+            spans.push(Span::raw(format!("  artist: {}", artist)));
+        }
+        if let Some(album) = &self.filter.album {
+            spans.push(Span::raw(format!("  album: {}", album)));
+        }
+        if !self.filter.query.is_empty() || matches!(self.mode, BrowserMode::EditQuery) {
+            spans.push(Span::raw(format!("  filter: {}", self.filter.query)));
+        }
+
+        let header = Paragraph::new(Line::from(spans))
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(ACCENT)));
+        frame.render_widget(header, area);
+    }
+
+    fn draw_footer(&self, frame: &mut Frame, area: Rect) {
+        let (text, style) = match (&self.mode, &self.message) {
+            (BrowserMode::EditQuery, _) => (
+                format!("/{}_   Enter done  Esc done", self.filter.query),
+                Style::default().fg(ACCENT),
+            ),
+            (BrowserMode::ConfirmDelete, _) => (
+                "Delete the lyrics files and embedded lyrics of this track? (y/N)".to_string(),
+                Style::default().fg(Color::Red),
+            ),
+            (_, Some(message)) => (message.clone(), Style::default().fg(ACCENT)),
+            _ => (
+                "↑/↓ select  / filter  Tab status  s search  d download  x delete  r reload  q quit".to_string(),
+                Style::default().fg(Color::DarkGray),
+            ),
+        };
+
+        frame.render_widget(Paragraph::new(text).style(style), area);
+    }
+}
+
+fn track_list_item(track: &DatabaseTrack) -> ListItem<'static> {
+    let (marker, color) = if track.instrumental {
+        ("[I]", Color::Cyan)
+    } else if track.lrc_lyrics.is_some() {
+        ("[S]", Color::Green)
+    } else if track.txt_lyrics.is_some() {
+        ("[P]", Color::Yellow)
+    } else {
+        ("[ ]", Color::Red)
+    };
+
+    ListItem::new(Line::from(vec![
+        Span::styled(marker, Style::default().fg(color)),
+        Span::raw(format!(" {} - {}", track.artist_name, track.title)),
+        Span::styled(format!("  {}", track.album_name), Style::default().fg(Color::DarkGray)),
+    ]))
+}
+
+/// Lyrics pane title and content for a track: synced lyrics first, then plain lyrics
+fn lyrics_view(track: &DatabaseTrack) -> (String, String) {
+    if track.instrumental {
+        (" Instrumental ".to_string(), "♪ Instrumental track ♪".to_string())
+    } else if let Some(lrc) = &track.lrc_lyrics {
+        (format!(" Synced lyrics · {} ", track.file_name), lrc.clone())
+    } else if let Some(txt) = &track.txt_lyrics {
+        (format!(" Plain lyrics · {} ", track.file_name), txt.clone())
+    } else {
+        (format!(" No lyrics · {} ", track.file_name), "No lyrics yet. Press s to search or d to download.".to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn track(artist: &str, album: &str, lrc: Option<&str>, instrumental: bool) -> DatabaseTrack {
+        DatabaseTrack {
+            id: 1,
+            file_path: "/music/song.mp3".to_string(),
+            file_name: "song.mp3".to_string(),
+            title: "Song".to_string(),
+            album_name: album.to_string(),
+            artist_name: artist.to_string(),
+            album_artist: artist.to_string(),
+            duration: 180.0,
+            track_number: None,
+            txt_lyrics: None,
+            lrc_lyrics: lrc.map(str::to_string),
+            txt_lyrics_source: None,
+            lrc_lyrics_source: None,
+            instrumental,
+        }
+    }
+
+    #[test]
+    fn test_library_filter_matches_fields_and_status() {
+        let mut filter = LibraryFilter {
+            artist: Some("queen".to_string()),
+            album: None,
+            status: StatusFilter::Missing,
+            query: String::new(),
+        };
+
+        assert!(filter.matches(&track("Queen", "A Night at the Opera", None, false)));
+        assert!(!filter.matches(&track("Queen", "A Night at the Opera", Some("[00:01.00]x"), false)));
+        assert!(!filter.matches(&track("Queen", "A Night at the Opera", None, true)));
+        assert!(!filter.matches(&track("ABBA", "Arrival", None, false)));
+
+        filter.status = StatusFilter::Synced;
+        filter.query = "opera".to_string();
+        assert!(filter.matches(&track("Queen", "A Night at the Opera", Some("[00:01.00]x"), false)));
+        assert!(!filter.matches(&track("Queen", "Jazz", Some("[00:01.00]x"), false)));
+    }
+}
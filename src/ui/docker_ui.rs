@@ -20,9 +20,13 @@ impl DockerUi {
         let _ = io::stdout().flush();
     }
 
-    fn log_progress(&self, processed: usize, total: usize, percentage: f64) {
+    fn log_progress(&self, processed: usize, total: usize, percentage: f64, concurrency: usize) {
         let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S.%6fZ").to_string();
-        println!("{}  INFO {}/{} tracks completed ({:.1}%)", timestamp, processed, total, percentage);
+        if concurrency > 0 {
+            println!("{}  INFO {}/{} tracks completed ({:.1}%), {} parallel", timestamp, processed, total, percentage, concurrency);
+        } else {
+            println!("{}  INFO {}/{} tracks completed ({:.1}%)", timestamp, processed, total, percentage);
+        }
         let _ = io::stdout().flush();
     }
 
@@ -37,6 +41,7 @@ impl DockerUi {
         let (level, message) = match result {
             TrackResult::SyncedLyrics => ("INFO", format!("Downloaded synced lyrics for {}", track)),
             TrackResult::PlainLyrics => ("INFO", format!("Downloaded plain lyrics for {}", track)),
+            TrackResult::Instrumental => ("INFO", format!("Marked {} as instrumental", track)),
             TrackResult::NotFound => ("WARN", format!("No lyrics found for {}", track)),
             TrackResult::Error(err) => ("ERROR", format!("Error processing {}: {}", track, err)),
         };
@@ -52,7 +57,7 @@ impl ProgressInterface for DockerUi {
     }
 
     async fn update_progress(&mut self, state: &ProgressState) {
-        self.log_progress(state.processed_tracks, state.total_tracks, state.progress_percentage());
+        self.log_progress(state.processed_tracks, state.total_tracks, state.progress_percentage(), state.concurrency);
 
         // Log current track if available
         if let Some(ref track) = state.current_track {
@@ -93,11 +98,12 @@ impl ProgressInterface for DockerUi {
 
         // Log detailed statistics
         let timestamp = Utc::now().format("%Y-%m-%dT%H:%M:%S.%6fZ").to_string();
-        println!("{}  INFO Total: {} | Synced: {} | Plain: {} | Missing: {} | Errors: {}",
+        println!("{}  INFO Total: {} | Synced: {} | Plain: {} | Instrumental: {} | Missing: {} | Errors: {}",
             timestamp,
             final_stats.total_tracks,
             final_stats.synced_tracks,
             final_stats.plain_tracks,
+            final_stats.instrumental_tracks,
             final_stats.missing_tracks,
             final_stats.error_tracks
         );
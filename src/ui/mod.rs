@@ -1,6 +1,10 @@
 pub mod docker_ui;
 pub mod terminal_ui;
 pub mod progress_state;
+pub mod tui;
+pub mod playback;
+pub mod picker;
+pub mod browser;
 
 use async_trait::async_trait;
 use std::time::Instant;
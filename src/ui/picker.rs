@@ -0,0 +1,268 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap};
+use std::io;
+
+use crate::core::services::lrclib::SearchResult;
+use crate::ui::playback::{LyricsPlayback, PlaybackAction};
+use crate::ui::tui::{next_key, TuiSession, ACCENT, TICK};
+
+const PREVIEW_PAGE: u16 = 10;
+
+/// What the caller should do after a key press in the picker
+pub enum PickerAction {
+    None,
+    Select(usize),
+    Cancel,
+}
+
+/// Interactive list of search results with a lyrics preview pane.
+/// `confirm_label` names what Enter does in the help line.
+/// Returns the index of the chosen result, or `None` if the user cancelled.
+pub fn pick_search_result(results: &[SearchResult], header: &str, confirm_label: &'static str) -> io::Result<Option<usize>> {
+    if results.is_empty() {
+        return Ok(None);
+    }
+
+    let mut session = TuiSession::enter()?;
+    let mut picker = SearchPicker::new(results.to_vec(), header.to_string()).with_confirm_label(confirm_label);
+
+    loop {
+        session.terminal.draw(|frame| {
+            let area = frame.size();
+            picker.draw(frame, area);
+        })?;
+
+        if let Some(key) = next_key(TICK)? {
+            match picker.handle_key(key) {
+                PickerAction::Select(index) => return Ok(Some(index)),
+                PickerAction::Cancel => return Ok(None),
+                PickerAction::None => {}
+            }
+        }
+    }
+}
+
+/// Result list plus preview pane, usable on its own or inside another screen
+pub struct SearchPicker {
+    results: Vec<SearchResult>,
+    header: String,
+    list_state: ListState,
+    preview_scroll: u16,
+    playback: Option<LyricsPlayback>,
+    confirm_label: &'static str,
+}
+
+impl SearchPicker {
+    pub fn new(results: Vec<SearchResult>, header: String) -> Self {
+        let mut list_state = ListState::default();
+        list_state.select(Some(0));
+
+        Self {
+            results,
+            header,
+            list_state,
+            preview_scroll: 0,
+            playback: None,
+            confirm_label: "apply",
+        }
+    }
+
+    pub fn with_confirm_label(mut self, confirm_label: &'static str) -> Self {
+        self.confirm_label = confirm_label;
+        self
+    }
+
+    pub fn results(&self) -> &[SearchResult] {
+        &self.results
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> PickerAction {
+        if key.kind != KeyEventKind::Press || self.results.is_empty() {
+            return PickerAction::None;
+        }
+
+        if let Some(playback) = &mut self.playback {
+            if let PlaybackAction::Close = playback.handle_key(key) {
+                self.playback = None;
+            }
+            return PickerAction::None;
+        }
+
+        match key.code {
+            KeyCode::Up | KeyCode::Char('k') => self.select_previous(),
+            KeyCode::Down | KeyCode::Char('j') => self.select_next(),
+            KeyCode::Home => self.select(0),
+            KeyCode::End => self.select(self.results.len() - 1),
+            KeyCode::PageDown => self.preview_scroll = self.preview_scroll.saturating_add(PREVIEW_PAGE),
+            KeyCode::PageUp => self.preview_scroll = self.preview_scroll.saturating_sub(PREVIEW_PAGE),
+            KeyCode::Char('p') => self.start_playback(),
+            KeyCode::Enter => return PickerAction::Select(self.selected()),
+            KeyCode::Esc | KeyCode::Char('q') => return PickerAction::Cancel,
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return PickerAction::Cancel,
+            _ => {}
+        }
+
+        PickerAction::None
+    }
+
+    /// Open the timing preview for the selected result if it has synced lyrics
+    fn start_playback(&mut self) {
+        let Some(result) = self.results.get(self.selected()) else { return };
+        let Some(synced) = result.synced_lyrics.as_deref() else { return };
+
+        let title = format!(
+            "{} - {}",
+            result.artist_name.as_deref().unwrap_or("Unknown"),
+            result.name.as_deref().unwrap_or("Unknown"),
+        );
+        self.playback = LyricsPlayback::new(title, synced, result.duration);
+    }
+
+    fn selected(&self) -> usize {
+        self.list_state.selected().unwrap_or(0)
+    }
+
+    fn select(&mut self, index: usize) {
+        self.list_state.select(Some(index));
+        self.preview_scroll = 0;
+    }
+
+    fn select_next(&mut self) {
+        let next = (self.selected() + 1) % self.results.len();
+        self.select(next);
+    }
+
+    fn select_previous(&mut self) {
+        let previous = (self.selected() + self.results.len() - 1) % self.results.len();
+        self.select(previous);
+    }
+
+    pub fn draw(&mut self, frame: &mut Frame, area: Rect) {
+        if let Some(playback) = &self.playback {
+            playback.draw(frame, area);
+            return;
+        }
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(3), Constraint::Min(5), Constraint::Length(1)])
+            .split(area);
+
+        let header = Paragraph::new(self.header.as_str())
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(ACCENT)));
+        frame.render_widget(header, rows[0]);
+
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+            .split(rows[1]);
+
+        let items: Vec<ListItem> = self.results.iter().map(result_list_item).collect();
+        let list = List::new(items)
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(ACCENT))
+                .title(format!(" Results ({}) ", self.results.len())))
+            .highlight_style(Style::default().fg(Color::Black).bg(ACCENT))
+            .highlight_symbol("▶ ");
+        frame.render_stateful_widget(list, columns[0], &mut self.list_state);
+
+        let (title, lines) = match self.results.get(self.selected()) {
+            Some(selected) => preview_lines(selected),
+            None => (" Lyrics ", vec!["No results".to_string()]),
+        };
+        let preview = Paragraph::new(lines.join("\n"))
+            .block(Block::default()
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(ACCENT))
+                .title(title))
+            .wrap(Wrap { trim: false })
+            .scroll((self.preview_scroll, 0));
+        frame.render_widget(preview, columns[1]);
+
+        let help = Paragraph::new(format!(
+            "↑/↓ select  PgUp/PgDn scroll lyrics  p preview timing  Enter {}  Esc cancel",
+            self.confirm_label
+        ))
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(help, rows[2]);
+    }
+}
+
+fn result_list_item(result: &SearchResult) -> ListItem<'static> {
+    let kind = if result.instrumental {
+        "instr"
+    } else if result.synced_lyrics.is_some() {
+        "synced"
+    } else if result.plain_lyrics.is_some() {
+        "plain"
+    } else {
+        "none"
+    };
+    let duration = result.duration
+        .map(|d| format!("{}:{:02}", d as u64 / 60, d as u64 % 60))
+        .unwrap_or_else(|| "--:--".to_string());
+
+    ListItem::new(vec![
+        Line::from(format!(
+            "{} - {}",
+            result.artist_name.as_deref().unwrap_or("Unknown"),
+            result.name.as_deref().unwrap_or("Unknown"),
+        )),
+        Line::from(Span::styled(
+            format!(
+                "  {} · {} · {} · {}",
+                result.album_name.as_deref().unwrap_or("Unknown"),
+                duration,
+                kind,
+                result.source.as_str(),
+            ),
+            Style::default().fg(Color::DarkGray),
+        )),
+    ])
+}
+
+/// Preview pane title and content: synced lyrics first, then plain lyrics
+fn preview_lines(result: &SearchResult) -> (&'static str, Vec<String>) {
+    if result.instrumental {
+        (" Instrumental ", vec!["♪ Instrumental track ♪".to_string()])
+    } else if let Some(synced) = &result.synced_lyrics {
+        (" Synced lyrics ", synced.lines().map(str::to_string).collect())
+    } else if let Some(plain) = &result.plain_lyrics {
+        (" Plain lyrics ", plain.lines().map(str::to_string).collect())
+    } else {
+        (" Lyrics ", vec!["No lyrics in this result".to_string()])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::services::lrclib::SearchResultSource;
+
+    fn result(synced: Option<&str>, plain: Option<&str>) -> SearchResult {
+        SearchResult {
+            id: Some(1),
+            name: Some("Song".to_string()),
+            artist_name: Some("Artist".to_string()),
+            album_name: None,
+            duration: Some(61.0),
+            plain_lyrics: plain.map(str::to_string),
+            synced_lyrics: synced.map(str::to_string),
+            instrumental: false,
+            source: SearchResultSource::Api,
+        }
+    }
+
+    #[test]
+    fn test_preview_prefers_synced_lyrics() {
+        let (title, lines) = preview_lines(&result(Some("[00:01.00]One\n[00:02.00]Two"), Some("One\nTwo")));
+        assert_eq!(title, " Synced lyrics ");
+        assert_eq!(lines, vec!["[00:01.00]One", "[00:02.00]Two"]);
+
+        let (title, lines) = preview_lines(&result(None, Some("Only plain")));
+        assert_eq!(title, " Plain lyrics ");
+        assert_eq!(lines, vec!["Only plain"]);
+    }
+}
@@ -0,0 +1,238 @@
+use crossterm::event::{KeyCode, KeyEvent, KeyEventKind};
+use ratatui::prelude::*;
+use ratatui::widgets::{Block, Borders, Gauge, Paragraph};
+use std::time::Instant;
+
+use crate::core::files::lyrics::{format_lrc_timestamp, parse_timed_lines};
+use crate::ui::tui::ACCENT;
+
+const SEEK_STEP_MS: i64 = 5_000;
+/// How long the preview keeps running after the last line when the track duration is unknown
+const TAIL_MS: u64 = 5_000;
+
+/// What the caller should do after a key press in the timing preview
+pub enum PlaybackAction {
+    None,
+    Close,
+}
+
+/// Wall-clock position that can be paused and moved
+struct PlaybackClock {
+    base_ms: u64,
+    running_since: Option<Instant>,
+}
+
+impl PlaybackClock {
+    fn start(now: Instant) -> Self {
+        Self {
+            base_ms: 0,
+            running_since: Some(now),
+        }
+    }
+
+    fn position_ms(&self, now: Instant) -> u64 {
+        let elapsed = self.running_since
+            .map(|since| now.saturating_duration_since(since).as_millis() as u64)
+            .unwrap_or(0);
+        self.base_ms + elapsed
+    }
+
+    fn is_paused(&self) -> bool {
+        self.running_since.is_none()
+    }
+
+    fn toggle_pause(&mut self, now: Instant) {
+        self.base_ms = self.position_ms(now);
+        self.running_since = match self.running_since {
+            Some(_) => None,
+            None => Some(now),
+        };
+    }
+
+    fn seek_to(&mut self, position_ms: u64, now: Instant) {
+        self.base_ms = position_ms;
+        if self.running_since.is_some() {
+            self.running_since = Some(now);
+        }
+    }
+
+    fn seek_by(&mut self, delta_ms: i64, now: Instant) {
+        let target = (self.position_ms(now) as i64 + delta_ms).max(0) as u64;
+        self.seek_to(target, now);
+    }
+}
+
+/// Plays synced lyrics against a wall clock so their timing can be judged without audio
+pub struct LyricsPlayback {
+    title: String,
+    lines: Vec<(u32, String)>,
+    end_ms: u64,
+    clock: PlaybackClock,
+}
+
+impl LyricsPlayback {
+    /// Returns `None` when the lyrics carry no time tags
+    pub fn new(title: String, synced_lyrics: &str, duration: Option<f64>) -> Option<Self> {
+        let lines = parse_timed_lines(synced_lyrics);
+        let last_ms = lines.last()?.0 as u64;
+        let end_ms = duration
+            .filter(|d| *d > 0.0)
+            .map(|d| (d * 1000.0) as u64)
+            .unwrap_or(0)
+            .max(last_ms + TAIL_MS);
+
+        Some(Self {
+            title,
+            lines,
+            end_ms,
+            clock: PlaybackClock::start(Instant::now()),
+        })
+    }
+
+    fn position_ms(&self, now: Instant) -> u64 {
+        self.clock.position_ms(now).min(self.end_ms)
+    }
+
+    pub fn handle_key(&mut self, key: KeyEvent) -> PlaybackAction {
+        if key.kind != KeyEventKind::Press {
+            return PlaybackAction::None;
+        }
+
+        let now = Instant::now();
+        let finished = self.clock.position_ms(now) >= self.end_ms;
+        if finished {
+            // Stop at the end so seeking back is relative to the last position shown
+            self.clock.seek_to(self.end_ms, now);
+            if !self.clock.is_paused() {
+                self.clock.toggle_pause(now);
+            }
+        }
+
+        match key.code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => {
+                // Playing again after the end starts over
+                if finished {
+                    self.clock.seek_to(0, now);
+                }
+                self.clock.toggle_pause(now);
+            }
+            KeyCode::Left | KeyCode::Char('h') => self.clock.seek_by(-SEEK_STEP_MS, now),
+            KeyCode::Right | KeyCode::Char('l') => self.clock.seek_by(SEEK_STEP_MS, now),
+            KeyCode::Up | KeyCode::Char('k') => {
+                let current = current_line(&self.lines, self.position_ms(now));
+                let target = current.and_then(|i| i.checked_sub(1)).unwrap_or(0);
+                self.clock.seek_to(self.lines[target].0 as u64, now);
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                let next = current_line(&self.lines, self.position_ms(now)).map_or(0, |i| i + 1);
+                if let Some((time, _)) = self.lines.get(next) {
+                    self.clock.seek_to(*time as u64, now);
+                }
+            }
+            KeyCode::Home => self.clock.seek_to(0, now),
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Backspace => return PlaybackAction::Close,
+            _ => {}
+        }
+
+        PlaybackAction::None
+    }
+
+    pub fn draw(&self, frame: &mut Frame, area: Rect) {
+        let position = self.position_ms(Instant::now());
+        let current = current_line(&self.lines, position);
+
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(5), Constraint::Length(3), Constraint::Length(1)])
+            .split(area);
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(ACCENT))
+            .title(format!(" Timing preview · {} ", self.title));
+        let height = block.inner(rows[0]).height as usize;
+
+        // Keep the current line in the middle of the pane
+        let first = current.unwrap_or(0).saturating_sub(height / 2);
+        let text: Vec<Line> = self.lines
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(height)
+            .map(|(i, (time, text))| {
+                let style = match current {
+                    Some(c) if i == c => Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+                    Some(c) if i < c => Style::default().fg(Color::DarkGray),
+                    _ => Style::default(),
+                };
+                Line::from(vec![
+                    Span::styled(format_lrc_timestamp(*time), Style::default().fg(Color::DarkGray)),
+                    Span::styled(format!(" {}", text), style),
+                ])
+            })
+            .collect();
+        frame.render_widget(Paragraph::new(text).block(block), rows[0]);
+
+        let state = if position >= self.end_ms {
+            "Finished"
+        } else if self.clock.is_paused() {
+            "Paused"
+        } else {
+            "Playing"
+        };
+        let gauge = Gauge::default()
+            .block(Block::default().borders(Borders::ALL).border_style(Style::default().fg(ACCENT)))
+            .gauge_style(Style::default().fg(ACCENT))
+            .ratio((position as f64 / self.end_ms as f64).clamp(0.0, 1.0))
+            .label(format!("{}  {} / {}", state, format_clock(position), format_clock(self.end_ms)));
+        frame.render_widget(gauge, rows[1]);
+
+        let help = Paragraph::new("Space pause  ←/→ seek 5s  ↑/↓ previous/next line  Home restart  Esc back")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(help, rows[2]);
+    }
+}
+
+/// Index of the last line whose time tag has been reached
+fn current_line(lines: &[(u32, String)], position_ms: u64) -> Option<usize> {
+    lines
+        .partition_point(|(time, _)| *time as u64 <= position_ms)
+        .checked_sub(1)
+}
+
+fn format_clock(millis: u64) -> String {
+    format!("{}:{:02}", millis / 60_000, (millis / 1000) % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_current_line_follows_time_tags() {
+        let lines = vec![(1_000, "a".to_string()), (2_500, "b".to_string()), (4_000, "c".to_string())];
+        assert_eq!(current_line(&lines, 0), None);
+        assert_eq!(current_line(&lines, 1_000), Some(0));
+        assert_eq!(current_line(&lines, 3_999), Some(1));
+        assert_eq!(current_line(&lines, 60_000), Some(2));
+    }
+
+    #[test]
+    fn test_clock_pause_and_seek() {
+        let start = Instant::now();
+        let mut clock = PlaybackClock::start(start);
+        assert_eq!(clock.position_ms(start + Duration::from_millis(1_200)), 1_200);
+
+        clock.toggle_pause(start + Duration::from_millis(1_500));
+        assert!(clock.is_paused());
+        assert_eq!(clock.position_ms(start + Duration::from_millis(9_000)), 1_500);
+
+        clock.seek_by(-5_000, start + Duration::from_millis(9_000));
+        assert_eq!(clock.position_ms(start + Duration::from_millis(9_500)), 0);
+
+        clock.toggle_pause(start + Duration::from_millis(10_000));
+        clock.seek_by(5_000, start + Duration::from_millis(10_000));
+        assert_eq!(clock.position_ms(start + Duration::from_millis(10_250)), 5_250);
+    }
+}
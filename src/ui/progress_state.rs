@@ -8,10 +8,13 @@ pub struct ProgressState {
     pub processed_tracks: usize,
     pub synced_tracks: usize,
     pub plain_tracks: usize,
+    pub instrumental_tracks: usize,
     pub missing_tracks: usize,
     pub error_tracks: usize,
     pub current_operation: String,
     pub current_track: Option<String>,
+    /// Downloads currently allowed to run at once (0 if not known yet)
+    pub concurrency: usize,
     pub start_time: Instant,
 }
 
@@ -22,10 +25,12 @@ impl Default for ProgressState {
             processed_tracks: 0,
             synced_tracks: 0,
             plain_tracks: 0,
+            instrumental_tracks: 0,
             missing_tracks: 0,
             error_tracks: 0,
             current_operation: String::new(),
             current_track: None,
+            concurrency: 0,
             start_time: Instant::now(),
         }
     }
@@ -54,7 +59,7 @@ impl ProgressState {
         if self.processed_tracks == 0 {
             0.0
         } else {
-            let successful = self.synced_tracks + self.plain_tracks;
+            let successful = self.synced_tracks + self.plain_tracks + self.instrumental_tracks;
             (successful as f64 / self.processed_tracks as f64) * 100.0
         }
     }
@@ -80,13 +85,14 @@ impl ProgressState {
 pub enum TrackResult {
     SyncedLyrics,
     PlainLyrics,
+    Instrumental,
     NotFound,
     Error(String),
 }
 
 impl TrackResult {
     pub fn is_success(&self) -> bool {
-        matches!(self, TrackResult::SyncedLyrics | TrackResult::PlainLyrics)
+        matches!(self, TrackResult::SyncedLyrics | TrackResult::PlainLyrics | TrackResult::Instrumental)
     }
 
 
@@ -94,6 +100,7 @@ impl TrackResult {
         match self {
             TrackResult::SyncedLyrics => "Synced lyrics".to_string(),
             TrackResult::PlainLyrics => "Plain lyrics".to_string(),
+            TrackResult::Instrumental => "Instrumental".to_string(),
             TrackResult::NotFound => "Not found".to_string(),
             TrackResult::Error(err) => format!("Error: {}", err),
         }
@@ -106,6 +113,8 @@ pub struct FinalStats {
     pub total_tracks: usize,
     pub synced_tracks: usize,
     pub plain_tracks: usize,
+    #[serde(default)]
+    pub instrumental_tracks: usize,
     pub missing_tracks: usize,
     pub error_tracks: usize,
     pub success_rate: f64,
@@ -119,6 +128,7 @@ impl FinalStats {
             total_tracks: state.total_tracks,
             synced_tracks: state.synced_tracks,
             plain_tracks: state.plain_tracks,
+            instrumental_tracks: state.instrumental_tracks,
             missing_tracks: state.missing_tracks,
             error_tracks: state.error_tracks,
             success_rate: state.success_rate(),
@@ -127,9 +137,9 @@ impl FinalStats {
         }
     }
 
-    /// Get total successful downloads
+    /// Get total successful downloads, counting tracks confirmed as instrumental
     pub fn successful_tracks(&self) -> usize {
-        self.synced_tracks + self.plain_tracks
+        self.synced_tracks + self.plain_tracks + self.instrumental_tracks
     }
 
     /// Get total failed downloads
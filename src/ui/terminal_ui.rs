@@ -193,8 +193,8 @@ impl TerminalUi {
         execute!(io::stdout(), cursor::MoveTo(0, 4))?;
 
         // Single line with all statistics - no title, no border
-        let stats_line = format!("Total: {} - Synced: {} - Plain: {} - Missing: {}",
-            state.total_tracks, state.synced_tracks, state.plain_tracks, state.missing_tracks);
+        let stats_line = format!("Total: {} - Synced: {} - Plain: {} - Instrumental: {} - Missing: {}",
+            state.total_tracks, state.synced_tracks, state.plain_tracks, state.instrumental_tracks, state.missing_tracks);
         self.draw_content_line(&stats_line)?;
 
         Ok(())
@@ -246,10 +246,17 @@ impl TerminalUi {
             "--:--".to_string()
         };
 
-        let footer_content = format!(
-            "Speed: {:.1} tracks/sec | ETA: {} | Elapsed: {}",
-            speed, eta_str, elapsed
-        );
+        let footer_content = if state.concurrency > 0 {
+            format!(
+                "Speed: {:.1} tracks/sec | Parallel: {} | ETA: {} | Elapsed: {}",
+                speed, state.concurrency, eta_str, elapsed
+            )
+        } else {
+            format!(
+                "Speed: {:.1} tracks/sec | ETA: {} | Elapsed: {}",
+                speed, eta_str, elapsed
+            )
+        };
 
         self.draw_content_line(&footer_content)?;
 
@@ -344,6 +351,9 @@ impl ProgressInterface for TerminalUi {
         if final_stats.plain_tracks > 0 {
             println!("Plain Lyrics: {} tracks", final_stats.plain_tracks);
         }
+        if final_stats.instrumental_tracks > 0 {
+            println!("Instrumental: {} tracks", final_stats.instrumental_tracks);
+        }
         if final_stats.missing_tracks > 0 {
             println!("Not Found: {} tracks", final_stats.missing_tracks);
         }
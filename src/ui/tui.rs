@@ -0,0 +1,57 @@
+use crossterm::{
+    cursor,
+    event::{self, Event, KeyEvent},
+    execute,
+    terminal::{self, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::prelude::{CrosstermBackend, Terminal};
+use std::io::{self, Stdout};
+use std::time::Duration;
+
+/// Orange accent shared with the progress UI
+pub const ACCENT: ratatui::style::Color = ratatui::style::Color::Rgb(255, 165, 0);
+
+/// Redraw interval for screens that animate, such as the lyrics timing preview
+pub const TICK: Duration = Duration::from_millis(50);
+
+/// Full-screen ratatui session; the terminal is restored when it is dropped,
+/// including on early returns and errors.
+pub struct TuiSession {
+    pub terminal: Terminal<CrosstermBackend<Stdout>>,
+}
+
+impl TuiSession {
+    pub fn enter() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        let mut stdout = io::stdout();
+        if let Err(e) = execute!(stdout, EnterAlternateScreen, cursor::Hide) {
+            let _ = terminal::disable_raw_mode();
+            return Err(e);
+        }
+
+        let terminal = Terminal::new(CrosstermBackend::new(stdout))?;
+        Ok(Self { terminal })
+    }
+}
+
+impl Drop for TuiSession {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen, cursor::Show);
+    }
+}
+
+/// Whether stdin and stdout are both attached to a terminal
+pub fn is_interactive() -> bool {
+    atty::is(atty::Stream::Stdin) && atty::is(atty::Stream::Stdout)
+}
+
+/// Wait up to `timeout` for a key press; `None` means the screen should just be redrawn
+pub fn next_key(timeout: Duration) -> io::Result<Option<KeyEvent>> {
+    if event::poll(timeout)? {
+        if let Event::Key(key) = event::read()? {
+            return Ok(Some(key));
+        }
+    }
+    Ok(None)
+}
@@ -3,6 +3,9 @@
 //! This module contains utility functions and helpers used throughout the application:
 //! - `logging`: Logging configuration and setup
 //! - `progress`: Progress bar utilities for consistent UI feedback
+//! - `test_dir`: Temporary directories for tests
 
 pub mod logging;
-pub mod progress;
\ No newline at end of file
+pub mod progress;
+#[cfg(test)]
+pub mod test_dir;
\ No newline at end of file